@@ -5,7 +5,7 @@ use criterion::{black_box, criterion_group, criterion_main, BenchmarkGroup, Crit
 use rand::distributions::{Distribution, Standard};
 use rand::rngs::SmallRng;
 use rand::SeedableRng;
-use reed_solomon_erasure::galois_8::ReedSolomon;
+use reed_solomon_erasure::galois_8::{self, ReedSolomon};
 
 type Shards = Vec<Vec<u8>>;
 
@@ -85,6 +85,24 @@ fn rs_reconstruct_benchmark(
     });
 }
 
+fn mul_slice_xor(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Galois 8 mul_slice_xor");
+    for &len in [1024, 16384].iter() {
+        group.throughput(criterion::Throughput::Bytes(len as u64));
+        group.bench_function(format!("{}B", len), |b| {
+            let mut small_rng = SmallRng::from_entropy();
+            let input: Vec<u8> = Standard.sample_iter(&mut small_rng).take(len).collect();
+            let mut out = vec![0; len];
+            let mut coeff = 0u8;
+
+            b.iter(|| {
+                coeff = coeff.wrapping_add(1);
+                galois_8::mul_slice_xor(coeff, black_box(&input), &mut out);
+            });
+        });
+    }
+}
+
 fn encode(c: &mut Criterion) {
     {
         let mut group = c.benchmark_group("Galos 8 [1KiB] Encode");
@@ -189,5 +207,12 @@ fn reconstruct_none(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, encode, reconstruct_one, reconstruct_all, reconstruct_none);
+criterion_group!(
+    benches,
+    mul_slice_xor,
+    encode,
+    reconstruct_one,
+    reconstruct_all,
+    reconstruct_none
+);
 criterion_main!(benches);
@@ -0,0 +1,84 @@
+use std::convert::TryInto;
+use std::sync::Barrier;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::distributions::{Distribution, Standard};
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+use reed_solomon_erasure::galois_8::ReedSolomon;
+
+const DATA_SHARDS: usize = 10;
+const PARITY_SHARDS: usize = 4;
+
+fn create_shards(rs: &ReedSolomon, block_size: usize) -> Vec<Vec<u8>> {
+    let mut small_rng = SmallRng::from_entropy();
+
+    let mut shards: Vec<Vec<u8>> = (0..DATA_SHARDS + PARITY_SHARDS)
+        .map(|_| {
+            Standard
+                .sample_iter(&mut small_rng)
+                .take(block_size)
+                .collect()
+        })
+        .collect();
+    rs.encode(&mut shards).unwrap();
+    shards
+}
+
+// Every thread reconstructs its own shards with a shared `ReedSolomon`,
+// cycling through a few erasure patterns so every call hits the decode matrix
+// cache. Small blocks keep the time spent coding low, to expose the
+// contention on the cache.
+fn reconstruct_threads(c: &mut Criterion, block_size: usize) {
+    let mut group = c.benchmark_group(format!("Galois 8 [{}B] Reconstruct Concurrent", block_size));
+    let rs = ReedSolomon::new(DATA_SHARDS, PARITY_SHARDS).unwrap();
+
+    for &threads in [1, 2, 4, 8, 16, 32, 64].iter() {
+        let size = block_size * DATA_SHARDS * threads;
+        group.throughput(criterion::Throughput::Bytes(size.try_into().unwrap()));
+
+        group.bench_function(format!("{} threads", threads), |b| {
+            b.iter_custom(|iters| {
+                let barrier = Barrier::new(threads);
+                // From the first thread starting to the last one finishing.
+                let spans: Vec<(Instant, Instant)> = thread::scope(|s| {
+                    let handles: Vec<_> = (0..threads)
+                        .map(|_| {
+                            s.spawn(|| {
+                                let expect = create_shards(&rs, block_size);
+                                let mut shards: Vec<Option<Vec<u8>>> =
+                                    expect.iter().cloned().map(Some).collect();
+                                barrier.wait();
+                                let start = Instant::now();
+                                for i in 0..iters as usize {
+                                    shards[i % DATA_SHARDS] = None;
+                                    shards[(i + 3) % DATA_SHARDS] = None;
+                                    rs.reconstruct(black_box(&mut shards)).unwrap();
+                                }
+                                (start, Instant::now())
+                            })
+                        })
+                        .collect();
+                    handles.into_iter().map(|h| h.join().unwrap()).collect()
+                });
+                let start = spans.iter().map(|span| span.0).min().unwrap();
+                let end = spans.iter().map(|span| span.1).max().unwrap();
+                end - start
+            });
+        });
+    }
+}
+
+fn reconstruct_concurrent(c: &mut Criterion) {
+    reconstruct_threads(c, 64);
+    reconstruct_threads(c, 1024);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().measurement_time(Duration::from_secs(10));
+    targets = reconstruct_concurrent
+}
+criterion_main!(benches);
@@ -9,17 +9,13 @@ use smallvec::SmallVec;
 use crate::errors::Error;
 use crate::errors::SBSError;
 
+use crate::decode_cache::DecodeCache;
 use crate::matrix::Matrix;
-
-use lru::LruCache;
-
-#[cfg(feature = "std")]
-use parking_lot::Mutex;
-#[cfg(not(feature = "std"))]
-use spin::Mutex;
+use crate::shard_set::ShardSet;
 
 use super::Field;
 use super::ReconstructShard;
+use super::ShardState;
 
 const DATA_DECODE_MATRIX_CACHE_CAPACITY: usize = 254;
 
@@ -127,7 +123,9 @@ impl<'a, F: 'a + Field> ShardByShard<'a, F> {
     /// but parity shards are not ready to use.
     pub fn reset(&mut self) -> Result<(), SBSError> {
         if self.cur_input > 0 && !self.parity_ready() {
-            return Err(SBSError::LeftoverShards);
+            return Err(SBSError::LeftoverShards {
+                remaining: self.codec.data_shard_count - self.cur_input,
+            });
         }
 
         self.cur_input = 0;
@@ -206,7 +204,9 @@ impl<'a, F: 'a + Field> ShardByShard<'a, F> {
         let shards = shards.as_mut();
         self.sbs_encode_checks(shards)?;
 
-        self.codec.encode_single(self.cur_input, shards).unwrap();
+        self.codec
+            .encode_single(self.cur_input, shards)
+            .map_err(SBSError::RSError)?;
 
         self.return_ok_and_incre_cur_input()
     }
@@ -224,16 +224,28 @@ impl<'a, F: 'a + Field> ShardByShard<'a, F> {
 
         self.codec
             .encode_single_sep(self.cur_input, data[self.cur_input].as_ref(), parity)
-            .unwrap();
+            .map_err(SBSError::RSError)?;
 
         self.return_ok_and_incre_cur_input()
     }
 }
 
+// The kind of coding matrix a codec is built with. Codecs of the same kind,
+// field and shard counts have the same coding matrix, so comparing the kind
+// stands in for comparing the matrices themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MatrixKind {
+    // See `build_matrix`.
+    Vandermonde,
+}
+
 /// Reed-Solomon erasure code encoder/decoder.
 ///
 /// # Common error handling
 ///
+/// Errors carry the offending shard index or the counts involved,
+/// use `Error::kind` to match on the kind of error alone.
+///
 /// ## For `encode`, `encode_shards`, `verify`, `verify_shards`, `reconstruct`, `reconstruct_data`, `reconstruct_shards`, `reconstruct_data_shards`
 ///
 /// Return `Error::TooFewShards` or `Error::TooManyShards`
@@ -246,13 +258,18 @@ impl<'a, F: 'a + Field> ShardByShard<'a, F> {
 /// Return `Error::IncorrectShardSize` when the provided shards
 /// are of different lengths.
 ///
-/// ## For `reconstruct`, `reconstruct_data`, `reconstruct_shards`, `reconstruct_data_shards`
+/// ## For `reconstruct`, `reconstruct_data`, `reconstruct_shards`, `reconstruct_data_shards`, `reconstruct_with_flags`, `reconstruct_with_bitset`
 ///
 /// Return `Error::TooFewShardsPresent` when there are not
 /// enough shards for reconstruction.
 ///
-/// Return `Error::InvalidShardFlags` when the number of flags does not match
-/// the total number of shards.
+/// Return `Error::UnavailableShard` when `reconstruct` or `reconstruct_data`
+/// is given a missing data shard without a buffer to reconstruct it into.
+///
+/// Return `Error::InvalidShardFlags` when the number of flags passed to
+/// `reconstruct_with_flags` does not match the total number of shards, or
+/// when the `ShardSet` passed to `reconstruct_with_bitset` holds an index
+/// past the last shard.
 ///
 /// # Variants of encoding methods
 ///
@@ -340,19 +357,41 @@ impl<'a, F: 'a + Field> ShardByShard<'a, F> {
 /// `Error::TooFewBufferShards`, `Error::TooManyBufferShards`, `Error::EmptyShard`,
 /// or `Error::IncorrectShardSize` when applicable.
 ///
+/// # Variants of reconstructing methods
+///
+/// `reconstruct` and `reconstruct_data` take every shard mutably and
+/// reconstruct the missing ones in place.
+///
+/// `reconstruct_sep` only reads the present shards, and writes the
+/// requested ones into separate output buffers. This allows reconstructing
+/// from read-only memory, e.g. memory mapped files.
+///
+/// `reconstruct_from_indexed` takes the shards as `(index, shard)` pairs
+/// in any order, e.g. as they arrive over the network, and returns the
+/// data shards.
+///
 #[derive(Debug)]
 pub struct ReedSolomon<F: Field> {
     data_shard_count: usize,
     parity_shard_count: usize,
     total_shard_count: usize,
-    matrix: Matrix<F>,
-    data_decode_matrix_cache: Mutex<LruCache<Vec<usize>, Arc<Matrix<F>>>>,
+    matrix_kind: MatrixKind,
+    // Shared between clones, so cloning is cheap and clones share the warm
+    // decode matrix cache.
+    matrix: Arc<Matrix<F>>,
+    data_decode_matrix_cache: Arc<DecodeCache<Matrix<F>>>,
 }
 
 impl<F: Field> Clone for ReedSolomon<F> {
     fn clone(&self) -> ReedSolomon<F> {
-        ReedSolomon::new(self.data_shard_count, self.parity_shard_count)
-            .expect("basic checks already passed as precondition of existence of self")
+        ReedSolomon {
+            data_shard_count: self.data_shard_count,
+            parity_shard_count: self.parity_shard_count,
+            total_shard_count: self.total_shard_count,
+            matrix_kind: self.matrix_kind,
+            matrix: self.matrix.clone(),
+            data_decode_matrix_cache: self.data_decode_matrix_cache.clone(),
+        }
     }
 }
 
@@ -360,6 +399,7 @@ impl<F: Field> PartialEq for ReedSolomon<F> {
     fn eq(&self, rhs: &ReedSolomon<F>) -> bool {
         self.data_shard_count == rhs.data_shard_count
             && self.parity_shard_count == rhs.parity_shard_count
+            && self.matrix_kind == rhs.matrix_kind
     }
 }
 
@@ -427,12 +467,27 @@ impl<F: Field> ReedSolomon<F> {
         parity_rows
     }
 
-    fn build_matrix(data_shards: usize, total_shards: usize) -> Matrix<F> {
-        let vandermonde = Matrix::vandermonde(total_shards, data_shards);
+    // The row re-creating parity shard `i_shard` from the shards the data
+    // decode matrix was built from.
+    fn parity_decode_row(&self, data_decode_matrix: &Matrix<F>, i_shard: usize) -> Vec<F::Elem> {
+        let mut row = vec![F::zero(); self.data_shard_count];
+        for (k, &c) in self.matrix.get_row(i_shard).iter().enumerate() {
+            F::mul_slice_add(c, data_decode_matrix.get_row(k), &mut row);
+        }
+
+        row
+    }
 
-        let top = vandermonde.sub_matrix(0, 0, data_shards, data_shards);
+    fn build_matrix(data_shards: usize, total_shards: usize) -> Result<Matrix<F>, Error> {
+        // `new` checks `total_shards <= F::ORDER`, so this does not fail.
+        let vandermonde = Matrix::vandermonde(total_shards, data_shards)?;
 
-        vandermonde.multiply(&top.invert().unwrap())
+        let top = vandermonde.sub_matrix(0, 0, data_shards, data_shards)?;
+
+        // Only singular if `F::nth` is not injective.
+        let top_inverse = top.invert()?;
+
+        vandermonde.multiply(&top_inverse)
     }
 
     /// Creates a new instance of Reed-Solomon erasure code encoder/decoder.
@@ -442,27 +497,45 @@ impl<F: Field> ReedSolomon<F> {
     /// Returns `Error::TooFewParityShards` if `parity_shards == 0`.
     ///
     /// Returns `Error::TooManyShards` if `data_shards + parity_shards > F::ORDER`.
+    ///
+    /// Returns `Error::SingularMatrix` if no coding matrix could be built,
+    /// which only happens for faulty `Field` implementations.
     pub fn new(data_shards: usize, parity_shards: usize) -> Result<ReedSolomon<F>, Error> {
         if data_shards == 0 {
-            return Err(Error::TooFewDataShards);
+            return Err(Error::TooFewDataShards {
+                expected: 1,
+                got: 0,
+            });
         }
         if parity_shards == 0 {
-            return Err(Error::TooFewParityShards);
+            return Err(Error::TooFewParityShards {
+                expected: 1,
+                got: 0,
+            });
         }
-        if data_shards + parity_shards > F::ORDER {
-            return Err(Error::TooManyShards);
+        // `got` saturates if the number of shards overflows.
+        let total_shards = data_shards
+            .checked_add(parity_shards)
+            .ok_or(Error::TooManyShards {
+                expected: F::ORDER,
+                got: usize::MAX,
+            })?;
+        if total_shards > F::ORDER {
+            return Err(Error::TooManyShards {
+                expected: F::ORDER,
+                got: total_shards,
+            });
         }
 
-        let total_shards = data_shards + parity_shards;
-
-        let matrix = Self::build_matrix(data_shards, total_shards);
+        let matrix = Self::build_matrix(data_shards, total_shards)?;
 
         Ok(ReedSolomon {
             data_shard_count: data_shards,
             parity_shard_count: parity_shards,
             total_shard_count: total_shards,
-            matrix,
-            data_decode_matrix_cache: Mutex::new(LruCache::new(DATA_DECODE_MATRIX_CACHE_CAPACITY)),
+            matrix_kind: MatrixKind::Vandermonde,
+            matrix: Arc::new(matrix),
+            data_decode_matrix_cache: Arc::new(DecodeCache::new(DATA_DECODE_MATRIX_CACHE_CAPACITY)),
         })
     }
 
@@ -478,6 +551,11 @@ impl<F: Field> ReedSolomon<F> {
         self.total_shard_count
     }
 
+    #[cfg(test)]
+    pub(crate) fn data_decode_matrix_cache_len(&self) -> usize {
+        self.data_decode_matrix_cache.len()
+    }
+
     fn code_some_slices<T: AsRef<[F::Elem]>, U: AsMut<[F::Elem]>>(
         &self,
         matrix_rows: &[&[F::Elem]],
@@ -651,6 +729,9 @@ impl<F: Field> ReedSolomon<F> {
     }
 
     /// Checks if the parity shards are correct.
+    ///
+    /// Errors about the shards of `buffer` index them as if they followed
+    /// `slices`.
     pub fn verify_with_buffer<T, U>(&self, slices: &[T], buffer: &mut [U]) -> Result<bool, Error>
     where
         T: AsRef<[F::Elem]>,
@@ -694,22 +775,396 @@ impl<F: Field> ReedSolomon<F> {
         self.reconstruct_internal(slices, true)
     }
 
-    fn get_data_decode_matrix(
+    /// Reconstructs all shards, with the shards that are present given by
+    /// `present` rather than by the shards themselves.
+    ///
+    /// Every shard must have a buffer of the same length, the ones not
+    /// present are overwritten with the reconstructed data.
+    ///
+    /// Returns `Error::InvalidShardFlags` if the number of flags does not
+    /// match the total number of shards.
+    pub fn reconstruct_with_flags<S: AsRef<[F::Elem]> + AsMut<[F::Elem]>>(
+        &self,
+        shards: &mut [S],
+        present: &[bool],
+    ) -> Result<(), Error> {
+        self.check_flags(present)?;
+        self.reconstruct_flagged(shards, |i| present[i], false)
+    }
+
+    /// Reconstructs only the data shards, with the shards that are present
+    /// given by `present`.
+    ///
+    /// See `reconstruct_with_flags`.
+    pub fn reconstruct_data_with_flags<S: AsRef<[F::Elem]> + AsMut<[F::Elem]>>(
+        &self,
+        shards: &mut [S],
+        present: &[bool],
+    ) -> Result<(), Error> {
+        self.check_flags(present)?;
+        self.reconstruct_flagged(shards, |i| present[i], true)
+    }
+
+    /// Reconstructs all shards, with the shards that are present given by a
+    /// `ShardSet`.
+    ///
+    /// Returns `Error::InvalidShardFlags` if `present` holds an index past
+    /// the last shard.
+    ///
+    /// Every shard must have a buffer of the same length, the ones not
+    /// present are overwritten with the reconstructed data.
+    pub fn reconstruct_with_bitset<S: AsRef<[F::Elem]> + AsMut<[F::Elem]>>(
+        &self,
+        shards: &mut [S],
+        present: &ShardSet,
+    ) -> Result<(), Error> {
+        self.check_bitset(present)?;
+        self.reconstruct_flagged(shards, |i| present.contains(i), false)
+    }
+
+    /// Reconstructs only the data shards, with the shards that are present
+    /// given by a `ShardSet`.
+    ///
+    /// See `reconstruct_with_bitset`.
+    pub fn reconstruct_data_with_bitset<S: AsRef<[F::Elem]> + AsMut<[F::Elem]>>(
+        &self,
+        shards: &mut [S],
+        present: &ShardSet,
+    ) -> Result<(), Error> {
+        self.check_bitset(present)?;
+        self.reconstruct_flagged(shards, |i| present.contains(i), true)
+    }
+
+    fn check_flags(&self, present: &[bool]) -> Result<(), Error> {
+        if present.len() != self.total_shard_count {
+            return Err(Error::InvalidShardFlags {
+                expected: self.total_shard_count,
+                got: present.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn check_bitset(&self, present: &ShardSet) -> Result<(), Error> {
+        match present.last() {
+            Some(last) if last >= self.total_shard_count => Err(Error::InvalidShardFlags {
+                expected: self.total_shard_count,
+                got: last + 1,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn reconstruct_flagged<S, P>(
+        &self,
+        shards: &mut [S],
+        present: P,
+        data_only: bool,
+    ) -> Result<(), Error>
+    where
+        S: AsRef<[F::Elem]> + AsMut<[F::Elem]>,
+        P: Fn(usize) -> bool,
+    {
+        check_piece_count!(all => self, shards);
+
+        let mut shards = shards
+            .iter_mut()
+            .enumerate()
+            .map(|(i, shard)| (shard.as_mut(), present(i)))
+            .collect::<SmallVec<[_; 32]>>();
+
+        self.reconstruct_internal(&mut shards, data_only)
+    }
+
+    /// Reconstructs the shards at the requested indices into separate output
+    /// buffers, leaving the present shards untouched.
+    ///
+    /// `present` holds all shards, with `None` for the missing ones.
+    /// Every entry of `outputs` is the index of a shard along with the
+    /// buffer to write it to, which must be of the same length as the
+    /// present shards. Any shard can be requested, present ones are copied.
+    ///
+    /// This is the counterpart of `encode_sep` for reconstruction, and only
+    /// needs read access to the present shards.
+    ///
+    /// Returns `Error::InvalidIndex` if an output index is out of range, and
+    /// `Error::IncorrectShardSize` if an output buffer is of the wrong size,
+    /// with the index of the requested shard as its index.
+    ///
+    /// Nothing is written if an `Error` is returned.
+    pub fn reconstruct_sep<T, U>(
         &self,
-        valid_indices: &[usize],
-        invalid_indices: &[usize],
-    ) -> Arc<Matrix<F>> {
+        present: &[Option<T>],
+        outputs: &mut [(usize, U)],
+    ) -> Result<(), Error>
+    where
+        T: AsRef<[F::Elem]>,
+        U: AsRef<[F::Elem]> + AsMut<[F::Elem]>,
+    {
+        self.reconstruct_sep_internal(present, outputs, false)
+    }
+
+    /// Reconstructs data shards at the requested indices into separate
+    /// output buffers, as `reconstruct_sep`.
+    ///
+    /// Returns `Error::InvalidIndex` if an output index is not that of a
+    /// data shard.
+    pub fn reconstruct_data_sep<T, U>(
+        &self,
+        present: &[Option<T>],
+        outputs: &mut [(usize, U)],
+    ) -> Result<(), Error>
+    where
+        T: AsRef<[F::Elem]>,
+        U: AsRef<[F::Elem]> + AsMut<[F::Elem]>,
+    {
+        self.reconstruct_sep_internal(present, outputs, true)
+    }
+
+    fn reconstruct_sep_internal<T, U>(
+        &self,
+        present: &[Option<T>],
+        outputs: &mut [(usize, U)],
+        data_only: bool,
+    ) -> Result<(), Error>
+    where
+        T: AsRef<[F::Elem]>,
+        U: AsRef<[F::Elem]> + AsMut<[F::Elem]>,
+    {
+        check_piece_count!(all => self, present);
+
+        let data_shard_count = self.data_shard_count;
+
+        let mut number_present = 0;
+        let mut shard_len = None;
+
+        for (index, shard) in present.iter().enumerate() {
+            if let Some(shard) = shard {
+                let len = shard.as_ref().len();
+                if len == 0 {
+                    return Err(Error::EmptyShard { index });
+                }
+                number_present += 1;
+                if let Some(old_len) = shard_len {
+                    if len != old_len {
+                        return Err(Error::IncorrectShardSize {
+                            index,
+                            expected: old_len,
+                            got: len,
+                        });
+                    }
+                }
+                shard_len = Some(len);
+            }
+        }
+
+        if number_present < data_shard_count {
+            return Err(Error::TooFewShardsPresent {
+                present: number_present,
+                needed: data_shard_count,
+            });
+        }
+
+        let shard_len = shard_len.expect("at least one shard present; qed");
+
+        let count = if data_only {
+            data_shard_count
+        } else {
+            self.total_shard_count
+        };
+        for (i_shard, output) in outputs.iter() {
+            if *i_shard >= count {
+                return Err(Error::InvalidIndex {
+                    index: *i_shard,
+                    count,
+                });
+            }
+            let got = output.as_ref().len();
+            if got != shard_len {
+                return Err(Error::IncorrectShardSize {
+                    index: *i_shard,
+                    expected: shard_len,
+                    got,
+                });
+            }
+        }
+
+        // Look up the data decode matrix before writing anything, as that
+        // can still fail.
+        let data_decode_matrix = if outputs
+            .iter()
+            .all(|(i_shard, _)| present[*i_shard].is_some())
         {
-            let mut cache = self.data_decode_matrix_cache.lock();
-            if let Some(entry) = cache.get(invalid_indices) {
-                return entry.clone();
+            None
+        } else {
+            let mut valid_indices = ShardSet::new();
+            let mut invalid_indices = ShardSet::new();
+            for (matrix_row, shard) in present.iter().enumerate() {
+                if shard.is_none() {
+                    invalid_indices.insert(matrix_row);
+                } else if valid_indices.len() < data_shard_count {
+                    valid_indices.insert(matrix_row);
+                }
+            }
+            Some(self.get_data_decode_matrix(&valid_indices, &invalid_indices)?)
+        };
+
+        // Copy the requested shards which are present, and set aside
+        // the missing ones.
+        let mut missing_slices = SmallVec::<[_; 32]>::new();
+        for (i_shard, output) in outputs.iter_mut() {
+            match &present[*i_shard] {
+                Some(shard) => output.as_mut().copy_from_slice(shard.as_ref()),
+                None => missing_slices.push((*i_shard, output.as_mut())),
             }
         }
+
+        let data_decode_matrix = match data_decode_matrix {
+            Some(data_decode_matrix) => data_decode_matrix,
+            None => return Ok(()),
+        };
+
+        let sub_shards: SmallVec<[&[F::Elem]; 32]> = present
+            .iter()
+            .flatten()
+            .take(data_shard_count)
+            .map(|shard| shard.as_ref())
+            .collect();
+
+        // The rows of the data decode matrix re-create the data shards from
+        // `sub_shards`. A parity shard is a combination of the data shards,
+        // so its row is the product of its row in the coding matrix with
+        // the data decode matrix, which saves re-creating the data shards
+        // first.
+        let parity_rows: SmallVec<[Vec<F::Elem>; 32]> = missing_slices
+            .iter()
+            .filter(|(i_shard, _)| *i_shard >= data_shard_count)
+            .map(|(i_shard, _)| self.parity_decode_row(&data_decode_matrix, *i_shard))
+            .collect();
+
+        let mut parity_rows = parity_rows.iter();
+        let matrix_rows: SmallVec<[&[F::Elem]; 32]> = missing_slices
+            .iter()
+            .map(|(i_shard, _)| {
+                if *i_shard < data_shard_count {
+                    data_decode_matrix.get_row(*i_shard)
+                } else {
+                    parity_rows.next().expect("one row per parity output; qed")
+                }
+            })
+            .collect();
+
+        let mut missing_slices: SmallVec<[&mut [F::Elem]; 32]> =
+            missing_slices.into_iter().map(|(_, slice)| slice).collect();
+
+        self.code_some_slices(&matrix_rows, &sub_shards, &mut missing_slices);
+
+        Ok(())
+    }
+
+    /// Reconstructs the data shards from shards given along with their
+    /// indices, in any order.
+    ///
+    /// Only the first `data_shard_count` shards yielded are used, the rest
+    /// of `shards` is not consumed.
+    ///
+    /// Returns `Error::InvalidIndex` if an index is out of range,
+    /// `Error::DuplicateShard` if a shard is given twice, and
+    /// `Error::TooFewShardsPresent` if there are not enough shards.
+    /// Shard size errors refer to shards by their index.
+    pub fn reconstruct_from_indexed<I, T>(&self, shards: I) -> Result<Vec<Vec<F::Elem>>, Error>
+    where
+        I: IntoIterator<Item = (usize, T)>,
+        T: AsRef<[F::Elem]>,
+    {
+        let present = self.collect_indexed(shards)?;
+        let shard_len = present
+            .iter()
+            .find_map(|shard| shard.as_ref().map(|shard| shard.as_ref().len()))
+            .expect("at least one shard present; qed");
+
+        let mut outputs: Vec<(usize, Vec<F::Elem>)> = (0..self.data_shard_count)
+            .map(|i| (i, vec![F::zero(); shard_len]))
+            .collect();
+        self.reconstruct_sep(&present, &mut outputs)?;
+
+        Ok(outputs.into_iter().map(|(_, shard)| shard).collect())
+    }
+
+    /// Reconstructs the data shards into `data` from shards given along with
+    /// their indices, in any order.
+    ///
+    /// See `reconstruct_from_indexed`. `data` must hold `data_shard_count`
+    /// buffers of the same length as the shards.
+    pub fn reconstruct_from_indexed_into<I, T, U>(
+        &self,
+        shards: I,
+        data: &mut [U],
+    ) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = (usize, T)>,
+        T: AsRef<[F::Elem]>,
+        U: AsRef<[F::Elem]> + AsMut<[F::Elem]>,
+    {
+        check_piece_count!(data => self, data);
+
+        let present = self.collect_indexed(shards)?;
+
+        let mut outputs = data
+            .iter_mut()
+            .enumerate()
+            .map(|(i, shard)| (i, shard.as_mut()))
+            .collect::<SmallVec<[_; 32]>>();
+        self.reconstruct_sep(&present, &mut outputs)
+    }
+
+    // Places the first `data_shard_count` distinct shards at their index.
+    fn collect_indexed<I, T>(&self, shards: I) -> Result<Vec<Option<T>>, Error>
+    where
+        I: IntoIterator<Item = (usize, T)>,
+        T: AsRef<[F::Elem]>,
+    {
+        let mut present: Vec<Option<T>> = (0..self.total_shard_count).map(|_| None).collect();
+        let mut number_present = 0;
+
+        let mut shards = shards.into_iter();
+        while number_present < self.data_shard_count {
+            let (index, shard) = match shards.next() {
+                Some(item) => item,
+                None => break,
+            };
+            check_slice_index!(all => self, index);
+            if present[index].is_some() {
+                return Err(Error::DuplicateShard { index });
+            }
+            present[index] = Some(shard);
+            number_present += 1;
+        }
+
+        if number_present < self.data_shard_count {
+            return Err(Error::TooFewShardsPresent {
+                present: number_present,
+                needed: self.data_shard_count,
+            });
+        }
+
+        Ok(present)
+    }
+
+    fn get_data_decode_matrix(
+        &self,
+        valid_indices: &ShardSet,
+        invalid_indices: &ShardSet,
+    ) -> Result<Arc<Matrix<F>>, Error> {
+        if let Some(entry) = self.data_decode_matrix_cache.get(invalid_indices) {
+            return Ok(entry);
+        }
         // Pull out the rows of the matrix that correspond to the shards that
         // we have and build a square matrix. This matrix could be used to
         // generate the shards that we have from the original data.
         let mut sub_matrix = Matrix::new(self.data_shard_count, self.data_shard_count);
-        for (sub_matrix_row, &valid_index) in valid_indices.iter().enumerate() {
+        for (sub_matrix_row, valid_index) in valid_indices.iter().enumerate() {
             for c in 0..self.data_shard_count {
                 sub_matrix.set(sub_matrix_row, c, self.matrix.get(valid_index, c));
             }
@@ -719,15 +1174,15 @@ impl<F: Field> ReedSolomon<F> {
         // we want to decode. Note that since this matrix maps back to the
         // original data, it can be used to create a data shard, but not a
         // parity shard.
-        let data_decode_matrix = Arc::new(sub_matrix.invert().unwrap());
+        //
+        // Any `data_shard_count` rows of the coding matrix are independent
+        // for a well-behaved field, so failing here means the field is faulty.
+        let data_decode_matrix = Arc::new(sub_matrix.invert()?);
         // Cache the inverted matrix for future use keyed on the indices of the
         // invalid rows.
-        {
-            let data_decode_matrix = data_decode_matrix.clone();
-            let mut cache = self.data_decode_matrix_cache.lock();
-            cache.put(Vec::from(invalid_indices), data_decode_matrix);
-        }
-        data_decode_matrix
+        Ok(self
+            .data_decode_matrix_cache
+            .insert(invalid_indices, data_decode_matrix))
     }
 
     fn reconstruct_internal<T: ReconstructShard<F>>(
@@ -744,16 +1199,20 @@ impl<F: Field> ReedSolomon<F> {
         let mut number_present = 0;
         let mut shard_len = None;
 
-        for shard in shards.iter_mut() {
+        for (index, shard) in shards.iter_mut().enumerate() {
             if let Some(len) = shard.len() {
                 if len == 0 {
-                    return Err(Error::EmptyShard);
+                    return Err(Error::EmptyShard { index });
                 }
                 number_present += 1;
                 if let Some(old_len) = shard_len {
                     if len != old_len {
                         // mismatch between shards.
-                        return Err(Error::IncorrectShardSize);
+                        return Err(Error::IncorrectShardSize {
+                            index,
+                            expected: old_len,
+                            got: len,
+                        });
                     }
                 }
                 shard_len = Some(len);
@@ -768,7 +1227,10 @@ impl<F: Field> ReedSolomon<F> {
 
         // More complete sanity check
         if number_present < data_shard_count {
-            return Err(Error::TooFewShardsPresent);
+            return Err(Error::TooFewShardsPresent {
+                present: number_present,
+                needed: data_shard_count,
+            });
         }
 
         let shard_len = shard_len.expect("at least one shard present; qed");
@@ -794,8 +1256,14 @@ impl<F: Field> ReedSolomon<F> {
             SmallVec::with_capacity(self.parity_shard_count);
         let mut missing_parity_slices: SmallVec<[&mut [F::Elem]; 32]> =
             SmallVec::with_capacity(self.parity_shard_count);
-        let mut valid_indices: SmallVec<[usize; 32]> = SmallVec::with_capacity(data_shard_count);
-        let mut invalid_indices: SmallVec<[usize; 32]> = SmallVec::with_capacity(data_shard_count);
+        let mut valid_indices = ShardSet::new();
+        let mut invalid_indices = ShardSet::new();
+        // Indices of the missing shards which have a buffer to reconstruct
+        // them into.
+        let mut missing_data_indices: SmallVec<[usize; 32]> =
+            SmallVec::with_capacity(self.parity_shard_count);
+        let mut missing_parity_indices: SmallVec<[usize; 32]> =
+            SmallVec::with_capacity(self.parity_shard_count);
 
         // Separate the shards into groups
         for (matrix_row, shard) in shards.iter_mut().enumerate() {
@@ -803,16 +1271,21 @@ impl<F: Field> ReedSolomon<F> {
             // but if we are only reconstructing data shard,
             // do not initialize if the shard is not a data shard
             let shard_data = if matrix_row >= data_shard_count && data_only {
-                shard.get().ok_or(None)
+                match shard.get() {
+                    Some(shard) => ShardState::Present(shard),
+                    None => ShardState::Unavailable,
+                }
             } else {
-                shard.get_or_initialize(shard_len).map_err(Some)
+                shard
+                    .get_or_initialize(shard_len)
+                    .map_err(|e| e.at_index(matrix_row))?
             };
 
             match shard_data {
-                Ok(shard) => {
+                ShardState::Present(shard) => {
                     if sub_shards.len() < data_shard_count {
                         sub_shards.push(shard);
-                        valid_indices.push(matrix_row);
+                        valid_indices.insert(matrix_row);
                     } else {
                         // Already have enough shards in `sub_shards`
                         // as we only need N shards, where N = `data_shard_count`,
@@ -821,26 +1294,32 @@ impl<F: Field> ReedSolomon<F> {
                         // So nothing to do here
                     }
                 }
-                Err(None) => {
+                ShardState::Unavailable => {
+                    // A parity shard is left as it is, but a data shard
+                    // would silently stay unrecovered.
+                    if matrix_row < data_shard_count {
+                        return Err(Error::UnavailableShard { index: matrix_row });
+                    }
                     // the shard data is not meant to be initialized here,
                     // but we should still note it missing.
-                    invalid_indices.push(matrix_row);
+                    invalid_indices.insert(matrix_row);
                 }
-                Err(Some(x)) => {
+                ShardState::Missing(shard) => {
                     // initialized missing shard data.
-                    let shard = x?;
                     if matrix_row < data_shard_count {
                         missing_data_slices.push(shard);
+                        missing_data_indices.push(matrix_row);
                     } else {
                         missing_parity_slices.push(shard);
+                        missing_parity_indices.push(matrix_row);
                     }
 
-                    invalid_indices.push(matrix_row);
+                    invalid_indices.insert(matrix_row);
                 }
             }
         }
 
-        let data_decode_matrix = self.get_data_decode_matrix(&valid_indices, &invalid_indices);
+        let data_decode_matrix = self.get_data_decode_matrix(&valid_indices, &invalid_indices)?;
 
         // Re-create any data shards that were missing.
         //
@@ -850,17 +1329,13 @@ impl<F: Field> ReedSolomon<F> {
         let mut matrix_rows: SmallVec<[&[F::Elem]; 32]> =
             SmallVec::with_capacity(self.parity_shard_count);
 
-        for i_slice in invalid_indices
-            .iter()
-            .cloned()
-            .take_while(|i| i < &data_shard_count)
-        {
+        for &i_slice in missing_data_indices.iter() {
             matrix_rows.push(data_decode_matrix.get_row(i_slice));
         }
 
         self.code_some_slices(&matrix_rows, &sub_shards, &mut missing_data_slices);
 
-        if data_only {
+        if data_only || missing_parity_slices.is_empty() {
             Ok(())
         } else {
             // Now that we have all of the data shards intact, we can
@@ -873,11 +1348,7 @@ impl<F: Field> ReedSolomon<F> {
                 SmallVec::with_capacity(self.parity_shard_count);
             let parity_rows = self.get_parity_rows();
 
-            for i_slice in invalid_indices
-                .iter()
-                .cloned()
-                .skip_while(|i| i < &data_shard_count)
-            {
+            for &i_slice in missing_parity_indices.iter() {
                 matrix_rows.push(parity_rows[i_slice - data_shard_count]);
             }
             {
@@ -902,11 +1373,7 @@ impl<F: Field> ReedSolomon<F> {
                     next_maybe_good = up_to + 1;
                 };
 
-                for i_slice in invalid_indices
-                    .iter()
-                    .cloned()
-                    .take_while(|i| i < &data_shard_count)
-                {
+                for &i_slice in missing_data_indices.iter() {
                     push_good_up_to(&mut all_data_slices, i_slice);
                     all_data_slices.push(missing_data_slices[i_new_data_slice]);
                     i_new_data_slice += 1;
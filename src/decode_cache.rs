@@ -0,0 +1,208 @@
+//! Cache of data decode matrices, shared by all threads using a `ReedSolomon`.
+//!
+//! Entries are spread over independently locked shards by the hash of their
+//! erasure pattern, and evicted with the CLOCK algorithm: a hit only sets the
+//! entry's reference bit, so lookups take a read lock and do not serialize
+//! behind each other. They still share the shard's lock word, one cache line
+//! that bounces between the cores hitting the same erasure pattern. The write
+//! lock is only taken to insert a newly inverted matrix.
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "std")]
+use parking_lot::RwLock;
+#[cfg(not(feature = "std"))]
+use spin::RwLock;
+
+use crate::shard_set::ShardSet;
+
+// Number of shards, a power of two.
+const SHARD_COUNT: usize = 16;
+
+pub(crate) struct DecodeCache<V> {
+    shards: Box<[RwLock<Shard<V>>]>,
+}
+
+struct Shard<V> {
+    entries: Vec<Entry<V>>,
+    capacity: usize,
+    // Next entry to consider for eviction.
+    hand: usize,
+}
+
+struct Entry<V> {
+    key: ShardSet,
+    value: Arc<V>,
+    // Set on every hit, cleared as the clock hand passes over the entry.
+    referenced: AtomicBool,
+}
+
+impl<V> DecodeCache<V> {
+    /// Creates a cache holding at least `capacity` entries in total.
+    pub(crate) fn new(capacity: usize) -> DecodeCache<V> {
+        let capacity = ((capacity + SHARD_COUNT - 1) / SHARD_COUNT).max(1);
+        let shards = (0..SHARD_COUNT)
+            .map(|_| {
+                RwLock::new(Shard {
+                    entries: Vec::new(),
+                    capacity,
+                    hand: 0,
+                })
+            })
+            .collect();
+        DecodeCache { shards }
+    }
+
+    fn shard(&self, key: &ShardSet) -> &RwLock<Shard<V>> {
+        // Fibonacci hashing of the words, the top bits pick the shard.
+        let hash = key.words().iter().fold(0u64, |hash, &word| {
+            (hash.rotate_left(5) ^ word).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        });
+        &self.shards[(hash >> (64 - SHARD_COUNT.trailing_zeros())) as usize]
+    }
+
+    pub(crate) fn get(&self, key: &ShardSet) -> Option<Arc<V>> {
+        let shard = self.shard(key).read();
+        let entry = shard.entries.iter().find(|entry| entry.key == *key)?;
+        // Avoid dirtying the cache line when the bit is already set.
+        if !entry.referenced.load(Ordering::Relaxed) {
+            entry.referenced.store(true, Ordering::Relaxed);
+        }
+        Some(entry.value.clone())
+    }
+
+    /// Inserts an entry, unless another thread got there first. Returns the
+    /// value now in the cache.
+    pub(crate) fn insert(&self, key: &ShardSet, value: Arc<V>) -> Arc<V> {
+        let mut shard = self.shard(key).write();
+        if let Some(entry) = shard.entries.iter().find(|entry| entry.key == *key) {
+            return entry.value.clone();
+        }
+
+        let entry = Entry {
+            key: key.clone(),
+            value: value.clone(),
+            referenced: AtomicBool::new(false),
+        };
+        if shard.entries.len() < shard.capacity {
+            shard.entries.push(entry);
+            return value;
+        }
+
+        // Give every referenced entry a second chance, this terminates within
+        // one full turn of the hand as it clears the bits it passes over.
+        loop {
+            let hand = shard.hand;
+            shard.hand = (hand + 1) % shard.entries.len();
+            if !shard.entries[hand]
+                .referenced
+                .swap(false, Ordering::Relaxed)
+            {
+                shard.entries[hand] = entry;
+                return value;
+            }
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().entries.len())
+            .sum()
+    }
+}
+
+impl<V> fmt::Debug for DecodeCache<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DecodeCache")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::sync::Arc;
+
+    use super::{DecodeCache, SHARD_COUNT};
+    use crate::shard_set::ShardSet;
+
+    fn key(index: usize) -> ShardSet {
+        [index].iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_get_insert() {
+        let cache = DecodeCache::new(10);
+        assert_eq!(None, cache.get(&key(3)));
+
+        assert_eq!(3, *cache.insert(&key(3), Arc::new(3)));
+        assert_eq!(Some(Arc::new(3)), cache.get(&key(3)));
+        assert_eq!(None, cache.get(&key(4)));
+
+        // The first insert wins.
+        assert_eq!(3, *cache.insert(&key(3), Arc::new(30)));
+        assert_eq!(Some(Arc::new(3)), cache.get(&key(3)));
+        assert_eq!(1, cache.len());
+    }
+
+    #[test]
+    fn test_bounded() {
+        let cache = DecodeCache::new(SHARD_COUNT * 4);
+        for i in 0..10_000 {
+            cache.insert(&key(i), Arc::new(i));
+            assert_eq!(Some(Arc::new(i)), cache.get(&key(i)));
+        }
+        assert!(cache.len() <= SHARD_COUNT * 4);
+    }
+
+    #[test]
+    fn test_keeps_referenced_entries() {
+        // Two entries per shard.
+        let cache = DecodeCache::new(SHARD_COUNT * 2);
+        let hot = key(0);
+        let shard = cache.shard(&hot);
+        let mut cold = (1..)
+            .map(key)
+            .filter(|k| core::ptr::eq(cache.shard(k), shard));
+        let (a, b) = (cold.next().unwrap(), cold.next().unwrap());
+
+        cache.insert(&hot, Arc::new(0));
+        cache.insert(&a, Arc::new(1));
+        assert!(cache.get(&hot).is_some());
+
+        cache.insert(&b, Arc::new(2));
+        assert_eq!(Some(Arc::new(0)), cache.get(&hot));
+        assert_eq!(None, cache.get(&a));
+        assert_eq!(Some(Arc::new(2)), cache.get(&b));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_concurrent() {
+        let cache = DecodeCache::new(64);
+        std::thread::scope(|s| {
+            for t in 0..8 {
+                let cache = &cache;
+                s.spawn(move || {
+                    for i in 0..1000 {
+                        let k = (i * 7 + t) % 100;
+                        match cache.get(&key(k)) {
+                            Some(v) => assert_eq!(k, *v),
+                            None => assert_eq!(k, *cache.insert(&key(k), Arc::new(k))),
+                        }
+                    }
+                });
+            }
+        });
+        assert!(cache.len() <= 64);
+    }
+}
@@ -1,7 +1,84 @@
 use core::fmt::Formatter;
 
-#[derive(PartialEq, Debug, Clone, Copy)]
+/// Errors of the codec.
+///
+/// Counts are given as `expected` by the codec versus `got` from the caller.
+/// Shard `index`es are positions in the list of shards the offending shard was
+/// passed in, which is 0 for a method taking a single shard.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[non_exhaustive]
 pub enum Error {
+    TooFewShards {
+        expected: usize,
+        got: usize,
+    },
+    TooManyShards {
+        expected: usize,
+        got: usize,
+    },
+    TooFewDataShards {
+        expected: usize,
+        got: usize,
+    },
+    TooManyDataShards {
+        expected: usize,
+        got: usize,
+    },
+    TooFewParityShards {
+        expected: usize,
+        got: usize,
+    },
+    TooManyParityShards {
+        expected: usize,
+        got: usize,
+    },
+    TooFewBufferShards {
+        expected: usize,
+        got: usize,
+    },
+    TooManyBufferShards {
+        expected: usize,
+        got: usize,
+    },
+    IncorrectShardSize {
+        index: usize,
+        expected: usize,
+        got: usize,
+    },
+    TooFewShardsPresent {
+        present: usize,
+        needed: usize,
+    },
+    EmptyShard {
+        index: usize,
+    },
+    InvalidShardFlags {
+        expected: usize,
+        got: usize,
+    },
+    InvalidIndex {
+        index: usize,
+        count: usize,
+    },
+    SingularMatrix,
+    InvalidFieldElement {
+        index: usize,
+        order: usize,
+    },
+    DuplicateShard {
+        index: usize,
+    },
+    NotSquare,
+    IncompatibleDimensions,
+    UnavailableShard {
+        index: usize,
+    },
+}
+
+/// The kind of an `Error`, without its context.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
     TooFewShards,
     TooManyShards,
     TooFewDataShards,
@@ -15,139 +92,290 @@ pub enum Error {
     EmptyShard,
     InvalidShardFlags,
     InvalidIndex,
+    SingularMatrix,
+    InvalidFieldElement,
+    DuplicateShard,
+    NotSquare,
+    IncompatibleDimensions,
+    UnavailableShard,
 }
 
 impl Error {
-    fn to_string(&self) -> &str {
+    /// The kind of the error.
+    pub fn kind(&self) -> ErrorKind {
         match *self {
-            Error::TooFewShards=> "The number of provided shards is smaller than the one in codec",
-            Error::TooManyShards => "The number of provided shards is greater than the one in codec",
-            Error::TooFewDataShards => "The number of provided data shards is smaller than the one in codec",
-            Error::TooManyDataShards => "The number of provided data shards is greater than the one in codec",
-            Error::TooFewParityShards => "The number of provided parity shards is smaller than the one in codec",
-            Error::TooManyParityShards => "The number of provided parity shards is greater than the one in codec",
-            Error::TooFewBufferShards => "The number of provided buffer shards is smaller than the number of parity shards in codec",
-            Error::TooManyBufferShards => "The number of provided buffer shards is greater than the number of parity shards in codec",
-            Error::IncorrectShardSize => "At least one of the provided shards is not of the correct size",
-            Error::TooFewShardsPresent => "The number of shards present is smaller than number of parity shards, cannot reconstruct missing shards",
-            Error::EmptyShard => "The first shard provided is of zero length",
-            Error::InvalidShardFlags => "The number of flags does not match the total number of shards",
-            Error::InvalidIndex => "The data shard index provided is greater or equal to the number of data shards in codec",
+            Error::TooFewShards { .. } => ErrorKind::TooFewShards,
+            Error::TooManyShards { .. } => ErrorKind::TooManyShards,
+            Error::TooFewDataShards { .. } => ErrorKind::TooFewDataShards,
+            Error::TooManyDataShards { .. } => ErrorKind::TooManyDataShards,
+            Error::TooFewParityShards { .. } => ErrorKind::TooFewParityShards,
+            Error::TooManyParityShards { .. } => ErrorKind::TooManyParityShards,
+            Error::TooFewBufferShards { .. } => ErrorKind::TooFewBufferShards,
+            Error::TooManyBufferShards { .. } => ErrorKind::TooManyBufferShards,
+            Error::IncorrectShardSize { .. } => ErrorKind::IncorrectShardSize,
+            Error::TooFewShardsPresent { .. } => ErrorKind::TooFewShardsPresent,
+            Error::EmptyShard { .. } => ErrorKind::EmptyShard,
+            Error::InvalidShardFlags { .. } => ErrorKind::InvalidShardFlags,
+            Error::InvalidIndex { .. } => ErrorKind::InvalidIndex,
+            Error::SingularMatrix => ErrorKind::SingularMatrix,
+            Error::InvalidFieldElement { .. } => ErrorKind::InvalidFieldElement,
+            Error::DuplicateShard { .. } => ErrorKind::DuplicateShard,
+            Error::NotSquare => ErrorKind::NotSquare,
+            Error::IncompatibleDimensions => ErrorKind::IncompatibleDimensions,
+            Error::UnavailableShard { .. } => ErrorKind::UnavailableShard,
+        }
+    }
+
+    // Sets the index of the offending shard, for errors raised by code
+    // which only sees the shard itself.
+    pub(crate) fn at_index(self, index: usize) -> Error {
+        match self {
+            Error::IncorrectShardSize { expected, got, .. } => Error::IncorrectShardSize {
+                index,
+                expected,
+                got,
+            },
+            Error::EmptyShard { .. } => Error::EmptyShard { index },
+            e => e,
         }
     }
 }
 
 impl core::fmt::Display for Error {
     fn fmt(&self, f: &mut Formatter) -> Result<(), core::fmt::Error> {
-        write!(f, "{}", self.to_string())
+        match *self {
+            Error::TooFewShards { expected, got } => write!(f, "The number of provided shards ({}) is smaller than the one in codec ({})", got, expected),
+            Error::TooManyShards { expected, got } => write!(f, "The number of provided shards ({}) is greater than the one in codec ({})", got, expected),
+            Error::TooFewDataShards { expected, got } => write!(f, "The number of provided data shards ({}) is smaller than the one in codec ({})", got, expected),
+            Error::TooManyDataShards { expected, got } => write!(f, "The number of provided data shards ({}) is greater than the one in codec ({})", got, expected),
+            Error::TooFewParityShards { expected, got } => write!(f, "The number of provided parity shards ({}) is smaller than the one in codec ({})", got, expected),
+            Error::TooManyParityShards { expected, got } => write!(f, "The number of provided parity shards ({}) is greater than the one in codec ({})", got, expected),
+            Error::TooFewBufferShards { expected, got } => write!(f, "The number of provided buffer shards ({}) is smaller than the number of parity shards in codec ({})", got, expected),
+            Error::TooManyBufferShards { expected, got } => write!(f, "The number of provided buffer shards ({}) is greater than the number of parity shards in codec ({})", got, expected),
+            Error::IncorrectShardSize { index, expected, got } => write!(f, "Shard {} is of size {}, but should be of size {}", index, got, expected),
+            Error::TooFewShardsPresent { present, needed } => write!(f, "The number of shards present ({}) is smaller than the number of data shards ({}), cannot reconstruct missing shards", present, needed),
+            Error::EmptyShard { index } => write!(f, "Shard {} is of zero length", index),
+            Error::InvalidShardFlags { expected, got } => write!(f, "The number of flags ({}) does not match the total number of shards ({})", got, expected),
+            Error::InvalidIndex { index, count } => write!(f, "The shard index provided ({}) is greater or equal to the number of shards ({})", index, count),
+            Error::SingularMatrix => write!(f, "The matrix is singular"),
+            Error::InvalidFieldElement { index, order } => write!(f, "The index provided ({}) is greater or equal to the order of the field ({})", index, order),
+            Error::DuplicateShard { index } => write!(f, "Shard {} is provided more than once", index),
+            Error::NotSquare => write!(f, "The matrix is not square"),
+            Error::IncompatibleDimensions => write!(f, "The dimensions of the matrices do not match"),
+            Error::UnavailableShard { index } => write!(f, "Shard {} is missing and has no buffer to be reconstructed into", index),
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn description(&self) -> &str {
-        self.to_string()
-    }
-}
+impl std::error::Error for Error {}
 
-#[derive(PartialEq, Debug, Clone, Copy)]
+/// Errors of `ShardByShard`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[non_exhaustive]
 pub enum SBSError {
     TooManyCalls,
-    LeftoverShards,
+    LeftoverShards { remaining: usize },
     RSError(Error),
 }
 
-impl SBSError {
-    fn to_string(&self) -> &str {
+impl core::fmt::Display for SBSError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), core::fmt::Error> {
         match *self {
-            SBSError::TooManyCalls => "Too many calls",
-            SBSError::LeftoverShards => "Leftover shards",
-            SBSError::RSError(ref e) => e.to_string(),
+            SBSError::TooManyCalls => write!(f, "Too many calls"),
+            SBSError::LeftoverShards { remaining } => {
+                write!(
+                    f,
+                    "Leftover shards ({} data shards not yet encoded)",
+                    remaining
+                )
+            }
+            SBSError::RSError(ref e) => write!(f, "{}", e),
         }
     }
 }
 
-impl core::fmt::Display for SBSError {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), core::fmt::Error> {
-        write!(f, "{}", self.to_string())
+impl From<Error> for SBSError {
+    fn from(e: Error) -> SBSError {
+        SBSError::RSError(e)
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for SBSError {
-    fn description(&self) -> &str {
-        self.to_string()
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            SBSError::RSError(ref e) => Some(e),
+            _ => None,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::errors::Error;
-    use crate::errors::SBSError;
+    extern crate alloc;
+
+    use alloc::string::ToString;
+
+    use crate::errors::{Error, ErrorKind, SBSError};
 
     #[test]
     fn test_error_to_string_is_okay() {
         assert_eq!(
-            Error::TooFewShards.to_string(),
-            "The number of provided shards is smaller than the one in codec"
+            Error::TooFewShards {
+                expected: 5,
+                got: 4
+            }
+            .to_string(),
+            "The number of provided shards (4) is smaller than the one in codec (5)"
+        );
+        assert_eq!(
+            Error::TooManyShards {
+                expected: 5,
+                got: 6
+            }
+            .to_string(),
+            "The number of provided shards (6) is greater than the one in codec (5)"
+        );
+        assert_eq!(
+            Error::TooFewDataShards {
+                expected: 3,
+                got: 2
+            }
+            .to_string(),
+            "The number of provided data shards (2) is smaller than the one in codec (3)"
+        );
+        assert_eq!(
+            Error::TooManyDataShards {
+                expected: 3,
+                got: 4
+            }
+            .to_string(),
+            "The number of provided data shards (4) is greater than the one in codec (3)"
+        );
+        assert_eq!(
+            Error::TooFewParityShards {
+                expected: 2,
+                got: 1
+            }
+            .to_string(),
+            "The number of provided parity shards (1) is smaller than the one in codec (2)"
         );
         assert_eq!(
-            Error::TooManyShards.to_string(),
-            "The number of provided shards is greater than the one in codec"
+            Error::TooManyParityShards {
+                expected: 2,
+                got: 3
+            }
+            .to_string(),
+            "The number of provided parity shards (3) is greater than the one in codec (2)"
         );
         assert_eq!(
-            Error::TooFewDataShards.to_string(),
-            "The number of provided data shards is smaller than the one in codec"
+            Error::TooFewBufferShards { expected: 2, got: 1 }.to_string(),
+            "The number of provided buffer shards (1) is smaller than the number of parity shards in codec (2)"
         );
         assert_eq!(
-            Error::TooManyDataShards.to_string(),
-            "The number of provided data shards is greater than the one in codec"
+            Error::TooManyBufferShards { expected: 2, got: 3 }.to_string(),
+            "The number of provided buffer shards (3) is greater than the number of parity shards in codec (2)"
         );
         assert_eq!(
-            Error::TooFewParityShards.to_string(),
-            "The number of provided parity shards is smaller than the one in codec"
+            Error::IncorrectShardSize {
+                index: 3,
+                expected: 10,
+                got: 9
+            }
+            .to_string(),
+            "Shard 3 is of size 9, but should be of size 10"
         );
+        assert_eq!(Error::TooFewShardsPresent { present: 2, needed: 3 }.to_string(), "The number of shards present (2) is smaller than the number of data shards (3), cannot reconstruct missing shards");
         assert_eq!(
-            Error::TooManyParityShards.to_string(),
-            "The number of provided parity shards is greater than the one in codec"
+            Error::EmptyShard { index: 0 }.to_string(),
+            "Shard 0 is of zero length"
         );
         assert_eq!(
-            Error::TooFewBufferShards.to_string(),
-            "The number of provided buffer shards is smaller than the number of parity shards in codec"
+            Error::InvalidShardFlags {
+                expected: 5,
+                got: 4
+            }
+            .to_string(),
+            "The number of flags (4) does not match the total number of shards (5)"
         );
         assert_eq!(
-            Error::TooManyBufferShards.to_string(),
-            "The number of provided buffer shards is greater than the number of parity shards in codec"
+            Error::InvalidIndex { index: 7, count: 3 }.to_string(),
+            "The shard index provided (7) is greater or equal to the number of shards (3)"
         );
+        assert_eq!(Error::SingularMatrix.to_string(), "The matrix is singular");
         assert_eq!(
-            Error::IncorrectShardSize.to_string(),
-            "At least one of the provided shards is not of the correct size"
+            Error::InvalidFieldElement {
+                index: 256,
+                order: 256
+            }
+            .to_string(),
+            "The index provided (256) is greater or equal to the order of the field (256)"
         );
-        assert_eq!(Error::TooFewShardsPresent.to_string(), "The number of shards present is smaller than number of parity shards, cannot reconstruct missing shards");
         assert_eq!(
-            Error::EmptyShard.to_string(),
-            "The first shard provided is of zero length"
+            Error::DuplicateShard { index: 4 }.to_string(),
+            "Shard 4 is provided more than once"
         );
+        assert_eq!(Error::NotSquare.to_string(), "The matrix is not square");
         assert_eq!(
-            Error::InvalidShardFlags.to_string(),
-            "The number of flags does not match the total number of shards"
+            Error::IncompatibleDimensions.to_string(),
+            "The dimensions of the matrices do not match"
         );
         assert_eq!(
-            Error::InvalidIndex.to_string(),
-            "The data shard index provided is greater or equal to the number of data shards in codec"
+            Error::UnavailableShard { index: 1 }.to_string(),
+            "Shard 1 is missing and has no buffer to be reconstructed into"
         );
     }
 
     #[test]
     fn test_sbserror_to_string_is_okay() {
         assert_eq!(SBSError::TooManyCalls.to_string(), "Too many calls");
-        assert_eq!(SBSError::LeftoverShards.to_string(), "Leftover shards");
+        assert_eq!(
+            SBSError::LeftoverShards { remaining: 2 }.to_string(),
+            "Leftover shards (2 data shards not yet encoded)"
+        );
+        assert_eq!(
+            SBSError::RSError(Error::EmptyShard { index: 1 }).to_string(),
+            "Shard 1 is of zero length"
+        );
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(
+            ErrorKind::IncorrectShardSize,
+            Error::IncorrectShardSize {
+                index: 0,
+                expected: 2,
+                got: 1
+            }
+            .kind()
+        );
+        assert_eq!(ErrorKind::SingularMatrix, Error::SingularMatrix.kind());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sbserror_source() {
+        use std::error::Error as _;
+
+        let e = Error::InvalidIndex { index: 4, count: 4 };
+        let sbs_error = SBSError::RSError(e);
+        let source = sbs_error.source().unwrap();
+        assert_eq!(Some(&e), source.downcast_ref::<Error>());
+        assert!(SBSError::TooManyCalls.source().is_none());
     }
 
     #[cfg(feature = "std")]
     #[test]
     fn test_error_display_does_not_panic() {
-        println!("{}", Error::TooFewShards);
+        println!(
+            "{}",
+            Error::TooFewShards {
+                expected: 1,
+                got: 0
+            }
+        );
     }
 
     #[cfg(feature = "std")]
@@ -0,0 +1,375 @@
+//! Conformance checks for implementations of `Field`.
+//!
+//! `check::<F>()` tests the field axioms on pseudo-random elements, along with
+//! the assumptions the codec makes on top of them: that the field is of
+//! characteristic 2, that `nth` is injective, and that the slice kernels agree
+//! with scalar `mul` and `add`. It is meant to be called from a test:
+//!
+//! ```
+//! use reed_solomon_erasure::field_conformance;
+//! use reed_solomon_erasure::galois_8;
+//!
+//! field_conformance::check::<galois_8::Field>();
+//! ```
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::Field;
+
+// Number of random elements (or tuples of elements) per property.
+const ROUNDS: usize = 1000;
+
+// Lengths of the buffers passed to the slice kernels.
+const SLICE_LENS: [usize; 6] = [0, 1, 7, 16, 64, 1000];
+
+// Fields up to this order have `nth` checked exhaustively.
+const EXHAUSTIVE_NTH_ORDER: usize = 256;
+
+macro_rules! ensure {
+    ($cond:expr, $($arg:tt)+) => {
+        if !$cond {
+            panic!($($arg)+);
+        }
+    };
+}
+
+// xorshift64*, enough to spread elements over the field without pulling in
+// a dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn index(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+
+    // An injective `nth` yields `ORDER` distinct elements, which is all of
+    // the field, even when `Elem` has values that are not field elements.
+    fn elem<F: Field>(&mut self) -> F::Elem {
+        F::nth(self.index(F::ORDER))
+    }
+
+    fn non_zero<F: Field>(&mut self) -> F::Elem {
+        loop {
+            let a = self.elem::<F>();
+            if a != F::zero() {
+                return a;
+            }
+        }
+    }
+}
+
+/// Check that `F` behaves as a field of characteristic 2, as expected by
+/// `ReedSolomon`.
+///
+/// # Panics
+/// Panics with a description of the first property that does not hold, along
+/// with the elements involved.
+pub fn check<F: Field>() {
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+
+    check_identities::<F>(&mut rng);
+    check_addition::<F>(&mut rng);
+    check_multiplication::<F>(&mut rng);
+    check_inverse::<F>(&mut rng);
+    check_exp::<F>(&mut rng);
+    check_nth::<F>(&mut rng);
+    check_slices::<F>(&mut rng);
+}
+
+fn check_identities<F: Field>(rng: &mut Rng) {
+    ensure!(F::zero() != F::one(), "zero and one are equal");
+
+    for _ in 0..ROUNDS {
+        let a = rng.elem::<F>();
+        ensure!(F::add(a, F::zero()) == a, "{:?} + 0 != {:?}", a, a);
+        ensure!(F::mul(a, F::one()) == a, "{:?} * 1 != {:?}", a, a);
+        ensure!(F::mul(a, F::zero()) == F::zero(), "{:?} * 0 != 0", a);
+    }
+}
+
+fn check_addition<F: Field>(rng: &mut Rng) {
+    for _ in 0..ROUNDS {
+        let (a, b, c) = (rng.elem::<F>(), rng.elem::<F>(), rng.elem::<F>());
+        ensure!(
+            F::add(a, F::add(b, c)) == F::add(F::add(a, b), c),
+            "addition is not associative for {:?}, {:?}, {:?}",
+            a,
+            b,
+            c
+        );
+        ensure!(
+            F::add(a, b) == F::add(b, a),
+            "addition is not commutative for {:?}, {:?}",
+            a,
+            b
+        );
+        ensure!(
+            F::add(a, a) == F::zero(),
+            "{:?} + {:?} != 0, the field is not of characteristic 2",
+            a,
+            a
+        );
+    }
+}
+
+fn check_multiplication<F: Field>(rng: &mut Rng) {
+    for _ in 0..ROUNDS {
+        let (a, b, c) = (rng.elem::<F>(), rng.elem::<F>(), rng.elem::<F>());
+        ensure!(
+            F::mul(a, F::mul(b, c)) == F::mul(F::mul(a, b), c),
+            "multiplication is not associative for {:?}, {:?}, {:?}",
+            a,
+            b,
+            c
+        );
+        ensure!(
+            F::mul(a, b) == F::mul(b, a),
+            "multiplication is not commutative for {:?}, {:?}",
+            a,
+            b
+        );
+        ensure!(
+            F::mul(a, F::add(b, c)) == F::add(F::mul(a, b), F::mul(a, c)),
+            "multiplication does not distribute over addition for {:?}, {:?}, {:?}",
+            a,
+            b,
+            c
+        );
+    }
+}
+
+fn check_inverse<F: Field>(rng: &mut Rng) {
+    for _ in 0..ROUNDS {
+        let a = rng.non_zero::<F>();
+        let b = rng.elem::<F>();
+        let inv = F::div(F::one(), a);
+        ensure!(F::mul(a, inv) == F::one(), "{:?} * (1 / {:?}) != 1", a, a);
+        ensure!(
+            F::div(F::mul(b, a), a) == b,
+            "({:?} * {:?}) / {:?} != {:?}",
+            b,
+            a,
+            a,
+            b
+        );
+        ensure!(
+            F::mul(b, a) != F::zero() || b == F::zero(),
+            "{:?} * {:?} == 0, the field has zero divisors",
+            b,
+            a
+        );
+    }
+}
+
+fn check_exp<F: Field>(rng: &mut Rng) {
+    for _ in 0..ROUNDS {
+        let a = rng.elem::<F>();
+        ensure!(F::exp(a, 0) == F::one(), "{:?}^0 != 1", a);
+
+        let n = rng.index(64);
+        let mut expect = F::one();
+        for _ in 0..n {
+            expect = F::mul(expect, a);
+        }
+        ensure!(
+            F::exp(a, n) == expect,
+            "{:?}^{} is not the product of {} factors",
+            a,
+            n,
+            n
+        );
+
+        // Large enough to wrap around the multiplicative group of any field
+        // with tables, i.e. up to GF(2^16).
+        let (n, m) = (rng.index(1 << 18), rng.index(1 << 18));
+        ensure!(
+            F::exp(a, n + m) == F::mul(F::exp(a, n), F::exp(a, m)),
+            "{:?}^({} + {}) != {:?}^{} * {:?}^{}",
+            a,
+            n,
+            m,
+            a,
+            n,
+            a,
+            m
+        );
+    }
+}
+
+fn check_nth<F: Field>(rng: &mut Rng) {
+    if F::ORDER <= EXHAUSTIVE_NTH_ORDER {
+        let elems: Vec<F::Elem> = (0..F::ORDER).map(F::nth).collect();
+        for (i, a) in elems.iter().enumerate() {
+            for (j, b) in elems.iter().enumerate().skip(i + 1) {
+                ensure!(a != b, "nth({}) == nth({}) == {:?}", i, j, a);
+            }
+        }
+    } else {
+        for _ in 0..ROUNDS {
+            let i = rng.index(F::ORDER);
+            // Nearby indices are the likeliest to collide, e.g. when an
+            // implementation masks off some of the low bits.
+            let j = if rng.next() & 1 == 0 {
+                rng.index(F::ORDER)
+            } else {
+                (i + 1 + rng.index(16)) % F::ORDER
+            };
+            ensure!(
+                i == j || F::nth(i) != F::nth(j),
+                "nth({}) == nth({}) == {:?}",
+                i,
+                j,
+                F::nth(i)
+            );
+        }
+    }
+}
+
+fn check_slices<F: Field>(rng: &mut Rng) {
+    for &len in SLICE_LENS.iter() {
+        for _ in 0..10 {
+            let c = rng.elem::<F>();
+            let input: Vec<F::Elem> = (0..len).map(|_| rng.elem::<F>()).collect();
+            let orig: Vec<F::Elem> = (0..len).map(|_| rng.elem::<F>()).collect();
+
+            let mut out = vec![F::zero(); len];
+            F::mul_slice(c, &input, &mut out);
+            for i in 0..len {
+                ensure!(
+                    out[i] == F::mul(c, input[i]),
+                    "mul_slice({:?}) differs from mul at index {} of {}",
+                    c,
+                    i,
+                    len
+                );
+            }
+
+            let mut out = orig.clone();
+            F::mul_slice_add(c, &input, &mut out);
+            for i in 0..len {
+                ensure!(
+                    out[i] == F::add(orig[i], F::mul(c, input[i])),
+                    "mul_slice_add({:?}) differs from mul and add at index {} of {}",
+                    c,
+                    i,
+                    len
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::marker::PhantomData;
+
+    use super::check;
+    use crate::{galois_16, galois_16_binary, galois_32, galois_8, Field};
+
+    #[test]
+    fn test_galois_8() {
+        check::<galois_8::Field>();
+        check::<galois_8::CustomField<0x11B>>();
+    }
+
+    #[test]
+    fn test_galois_16() {
+        check::<galois_16::Field>();
+        check::<galois_16_binary::Field>();
+    }
+
+    #[test]
+    fn test_galois_32() {
+        check::<galois_32::Field>();
+    }
+
+    // GF(2^8), with the methods a fixture breaks taken from `B`.
+    struct Broken<B>(PhantomData<B>);
+
+    trait Breakage {
+        fn nth_internal(n: usize) -> u8 {
+            n as u8
+        }
+
+        fn mul_slice_add(c: u8, input: &[u8], out: &mut [u8]) {
+            galois_8::mul_slice_xor(c, input, out)
+        }
+    }
+
+    impl<B: Breakage> Field for Broken<B> {
+        const ORDER: usize = 256;
+        type Elem = u8;
+
+        fn add(a: u8, b: u8) -> u8 {
+            galois_8::add(a, b)
+        }
+
+        fn mul(a: u8, b: u8) -> u8 {
+            galois_8::mul(a, b)
+        }
+
+        fn div(a: u8, b: u8) -> u8 {
+            galois_8::div(a, b)
+        }
+
+        fn exp(a: u8, n: usize) -> u8 {
+            galois_8::exp(a, n)
+        }
+
+        fn zero() -> u8 {
+            0
+        }
+
+        fn one() -> u8 {
+            1
+        }
+
+        fn nth_internal(n: usize) -> u8 {
+            B::nth_internal(n)
+        }
+
+        fn mul_slice_add(c: u8, input: &[u8], out: &mut [u8]) {
+            B::mul_slice_add(c, input, out)
+        }
+    }
+
+    struct BadSlices;
+
+    impl Breakage for BadSlices {
+        fn mul_slice_add(c: u8, input: &[u8], out: &mut [u8]) {
+            // Drops the last element.
+            let len = input.len().saturating_sub(1);
+            galois_8::mul_slice_xor(c, &input[..len], &mut out[..len]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "mul_slice_add")]
+    fn test_bad_slices() {
+        check::<Broken<BadSlices>>();
+    }
+
+    struct BadNth;
+
+    impl Breakage for BadNth {
+        // Not injective.
+        fn nth_internal(n: usize) -> u8 {
+            (n as u8) | 1
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "nth(")]
+    fn test_bad_nth() {
+        check::<Broken<BadNth>>();
+    }
+}
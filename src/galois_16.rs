@@ -2,9 +2,33 @@
 //!
 //! More accurately, this is a `GF((2^8)^2)` implementation which builds an extension
 //! field of `GF(2^8)`, as defined in the `galois_8` module.
+//!
+//! # Byte-oriented shards
+//!
+//! The codec works on shards of `[u8; 2]` symbols, but `ReedSolomon` over this field
+//! also provides `encode_bytes`, `verify_bytes`, `reconstruct_bytes`,
+//! `reconstruct_data_bytes`, `reconstruct_sep_bytes` and `reconstruct_data_sep_bytes`,
+//! which take plain byte shards.
+//!
+//! Every symbol is a pair of consecutive bytes, the first byte being the high-order
+//! coefficient, i.e. the same order as `[u8; 2]` in memory. Byte shards must therefore
+//! have an even length, otherwise `Error::IncorrectShardSize` is returned. Data of odd
+//! length should be padded with a trailing zero byte before encoding.
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::slice;
 
+use smallvec::SmallVec;
+use spin::Once;
+
+use crate::errors::Error;
 use crate::galois_8;
-use core::ops::{Add, Div, Mul, Sub};
+use crate::{ReconstructShard, ShardState};
 
 // the irreducible polynomial used as a modulus for the field.
 // print R.irreducible_element(2,algorithm="first_lexicographic" )
@@ -23,19 +47,19 @@ impl crate::Field for Field {
     type Elem = [u8; 2];
 
     fn add(a: [u8; 2], b: [u8; 2]) -> [u8; 2] {
-        (Element(a) + Element(b)).0
+        (Gf65536(a) + Gf65536(b)).0
     }
 
     fn mul(a: [u8; 2], b: [u8; 2]) -> [u8; 2] {
-        (Element(a) * Element(b)).0
+        (Gf65536(a) * Gf65536(b)).0
     }
 
     fn div(a: [u8; 2], b: [u8; 2]) -> [u8; 2] {
-        (Element(a) / Element(b)).0
+        (Gf65536(a) / Gf65536(b)).0
     }
 
     fn exp(elem: [u8; 2], n: usize) -> [u8; 2] {
-        Element(elem).exp(n).0
+        Gf65536(elem).pow(n).0
     }
 
     fn zero() -> [u8; 2] {
@@ -49,6 +73,14 @@ impl crate::Field for Field {
     fn nth_internal(n: usize) -> [u8; 2] {
         [(n >> 8) as u8, n as u8]
     }
+
+    fn mul_slice(c: [u8; 2], input: &[[u8; 2]], out: &mut [[u8; 2]]) {
+        mul_slice(c, input, out)
+    }
+
+    fn mul_slice_add(c: [u8; 2], input: &[[u8; 2]], out: &mut [[u8; 2]]) {
+        mul_slice_xor(c, input, out)
+    }
 }
 
 /// Type alias of ReedSolomon over GF(2^8).
@@ -57,19 +89,406 @@ pub type ReedSolomon = crate::ReedSolomon<Field>;
 /// Type alias of ShardByShard over GF(2^8).
 pub type ShardByShard<'a> = crate::ShardByShard<'a, Field>;
 
-/// An element of `GF(2^16)`.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-struct Element(pub [u8; 2]);
+/// View a byte slice as a slice of `GF(2^16)` symbols.
+///
+/// Returns `Error::IncorrectShardSize` if the length of `bytes` is odd, with
+/// the length padded to whole symbols as `expected`.
+pub fn as_symbols(bytes: &[u8]) -> Result<&[[u8; 2]], Error> {
+    check_even(bytes.len())?;
+
+    // `[u8; 2]` has the same alignment as `u8` and no padding.
+    Ok(unsafe { slice::from_raw_parts(bytes.as_ptr() as *const [u8; 2], bytes.len() / 2) })
+}
+
+/// View a mutable byte slice as a mutable slice of `GF(2^16)` symbols.
+///
+/// Returns `Error::IncorrectShardSize` if the length of `bytes` is odd, with
+/// the length padded to whole symbols as `expected`.
+pub fn as_symbols_mut(bytes: &mut [u8]) -> Result<&mut [[u8; 2]], Error> {
+    check_even(bytes.len())?;
+
+    // `[u8; 2]` has the same alignment as `u8` and no padding.
+    Ok(unsafe {
+        slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut [u8; 2], bytes.len() / 2)
+    })
+}
+
+fn check_even(len: usize) -> Result<(), Error> {
+    if len % 2 == 1 {
+        return Err(Error::IncorrectShardSize {
+            index: 0,
+            expected: len + 1,
+            got: len,
+        });
+    }
+    Ok(())
+}
+
+// Checks that byte shards, given as their indices and lengths, are all of the
+// same length and hold whole symbols. Checking before the shards are viewed
+// as symbols reports the offending shard, with sizes in bytes rather than
+// symbols. Returns the length, if there are any shards.
+fn check_byte_lens<I>(mut lens: I) -> Result<Option<usize>, Error>
+where
+    I: Iterator<Item = (usize, usize)>,
+{
+    let (first, expected) = match lens.next() {
+        Some(first) => first,
+        None => return Ok(None),
+    };
+    for (index, got) in lens {
+        if got != expected {
+            return Err(Error::IncorrectShardSize {
+                index,
+                expected,
+                got,
+            });
+        }
+    }
+
+    check_even(expected).map_err(|e| e.at_index(first))?;
+    Ok(Some(expected))
+}
+
+// Adapts a byte shard to a shard of symbols for reconstruction.
+// Odd lengths are rejected before the adapter is used.
+struct ByteShard<'a, T>(&'a mut T);
+
+impl<'a, T: ReconstructShard<galois_8::Field>> ReconstructShard<Field> for ByteShard<'a, T> {
+    fn len(&self) -> Option<usize> {
+        self.0.len().map(|len| len / 2)
+    }
+
+    fn get(&mut self) -> Option<&mut [[u8; 2]]> {
+        self.0.get().map(|x| as_symbols_mut(x).unwrap())
+    }
+
+    fn get_or_initialize(&mut self, len: usize) -> Result<ShardState<'_, [u8; 2]>, Error> {
+        Ok(match self.0.get_or_initialize(len * 2)? {
+            ShardState::Present(x) => ShardState::Present(as_symbols_mut(x).unwrap()),
+            ShardState::Missing(x) => ShardState::Missing(as_symbols_mut(x).unwrap()),
+            ShardState::Unavailable => ShardState::Unavailable,
+        })
+    }
+}
+
+impl crate::ReedSolomon<Field> {
+    /// Constructs the parity shards from byte shards.
+    ///
+    /// See the module documentation for the layout of the symbols.
+    pub fn encode_bytes<T, U>(&self, mut shards: T) -> Result<(), Error>
+    where
+        T: AsRef<[U]> + AsMut<[U]>,
+        U: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        check_byte_lens(shards.as_ref().iter().map(|s| s.as_ref().len()).enumerate())?;
+
+        let mut symbols: SmallVec<[&mut [[u8; 2]]; 32]> = SmallVec::new();
+        for shard in shards.as_mut().iter_mut() {
+            symbols.push(as_symbols_mut(shard.as_mut())?);
+        }
+
+        self.encode(symbols)
+    }
+
+    /// Checks if the parity shards are correct, for byte shards.
+    ///
+    /// See the module documentation for the layout of the symbols.
+    pub fn verify_bytes<T: AsRef<[u8]>>(&self, slices: &[T]) -> Result<bool, Error> {
+        check_byte_lens(slices.iter().map(|s| s.as_ref().len()).enumerate())?;
+
+        let mut symbols: SmallVec<[&[[u8; 2]]; 32]> = SmallVec::new();
+        for shard in slices.iter() {
+            symbols.push(as_symbols(shard.as_ref())?);
+        }
+
+        self.verify(&symbols)
+    }
+
+    /// Reconstructs all shards, for byte shards.
+    ///
+    /// Accepts the same shard types as reconstruction over `GF(2^8)`,
+    /// e.g. `Option<Vec<u8>>` or `(&mut [u8], bool)`.
+    ///
+    /// See the module documentation for the layout of the symbols.
+    pub fn reconstruct_bytes<T: ReconstructShard<galois_8::Field>>(
+        &self,
+        slices: &mut [T],
+    ) -> Result<(), Error> {
+        self.reconstruct_bytes_internal(slices, false)
+    }
+
+    /// Reconstructs only the data shards, for byte shards.
+    ///
+    /// Accepts the same shard types as reconstruction over `GF(2^8)`,
+    /// e.g. `Option<Vec<u8>>` or `(&mut [u8], bool)`.
+    ///
+    /// See the module documentation for the layout of the symbols.
+    pub fn reconstruct_data_bytes<T: ReconstructShard<galois_8::Field>>(
+        &self,
+        slices: &mut [T],
+    ) -> Result<(), Error> {
+        self.reconstruct_bytes_internal(slices, true)
+    }
+
+    /// Reconstructs the shards at the requested indices into separate output
+    /// buffers, for byte shards.
+    ///
+    /// See `reconstruct_sep`, and the module documentation for the layout of
+    /// the symbols.
+    pub fn reconstruct_sep_bytes<T, U>(
+        &self,
+        present: &[Option<T>],
+        outputs: &mut [(usize, U)],
+    ) -> Result<(), Error>
+    where
+        T: AsRef<[u8]>,
+        U: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        self.reconstruct_sep_bytes_internal(present, outputs, false)
+    }
+
+    /// Reconstructs data shards at the requested indices into separate
+    /// output buffers, for byte shards.
+    ///
+    /// See `reconstruct_data_sep`, and the module documentation for the
+    /// layout of the symbols.
+    pub fn reconstruct_data_sep_bytes<T, U>(
+        &self,
+        present: &[Option<T>],
+        outputs: &mut [(usize, U)],
+    ) -> Result<(), Error>
+    where
+        T: AsRef<[u8]>,
+        U: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        self.reconstruct_sep_bytes_internal(present, outputs, true)
+    }
+
+    fn reconstruct_sep_bytes_internal<T, U>(
+        &self,
+        present: &[Option<T>],
+        outputs: &mut [(usize, U)],
+        data_only: bool,
+    ) -> Result<(), Error>
+    where
+        T: AsRef<[u8]>,
+        U: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        let shard_len = check_byte_lens(
+            present
+                .iter()
+                .enumerate()
+                .filter_map(|(index, shard)| shard.as_ref().map(|s| (index, s.as_ref().len()))),
+        )?;
+        if let Some(expected) = shard_len {
+            for (i_shard, output) in outputs.iter() {
+                let got = output.as_ref().len();
+                if got != expected {
+                    return Err(Error::IncorrectShardSize {
+                        index: *i_shard,
+                        expected,
+                        got,
+                    });
+                }
+            }
+        }
+
+        let mut present_symbols = SmallVec::<[_; 32]>::new();
+        for shard in present.iter() {
+            present_symbols.push(match shard {
+                Some(shard) => Some(as_symbols(shard.as_ref())?),
+                None => None,
+            });
+        }
+
+        let mut output_symbols = SmallVec::<[_; 32]>::new();
+        for (i_shard, output) in outputs.iter_mut() {
+            output_symbols.push((*i_shard, as_symbols_mut(output.as_mut())?));
+        }
+
+        if data_only {
+            self.reconstruct_data_sep(&present_symbols, &mut output_symbols)
+        } else {
+            self.reconstruct_sep(&present_symbols, &mut output_symbols)
+        }
+    }
+
+    fn reconstruct_bytes_internal<T: ReconstructShard<galois_8::Field>>(
+        &self,
+        slices: &mut [T],
+        data_only: bool,
+    ) -> Result<(), Error> {
+        check_byte_lens(
+            slices
+                .iter()
+                .enumerate()
+                .filter_map(|(index, slice)| slice.len().map(|len| (index, len))),
+        )?;
+
+        let mut shards: SmallVec<[ByteShard<T>; 32]> = slices.iter_mut().map(ByteShard).collect();
+
+        if data_only {
+            self.reconstruct_data(&mut shards)
+        } else {
+            self.reconstruct(&mut shards)
+        }
+    }
+}
+
+// Number of elements de-interleaved into byte planes per round of the slice
+// kernels. The planes live on the stack, so this is kept small.
+const PLANE_LEN: usize = 256;
+
+// Shorter slices are multiplied element by element, which is faster than
+// setting up the planes for them.
+const PLANE_THRESHOLD: usize = 128;
+
+/// Multiply a slice of elements by `c`. Writes into the output slice.
+///
+/// The elements are split into planes of high and low bytes, which are then
+/// multiplied with the `GF(2^8)` slice kernels (including their SIMD versions).
+/// Short slices are multiplied element by element instead.
+///
+/// # Panics
+/// Panics if the output slice does not have equal length to the input.
+pub fn mul_slice(c: [u8; 2], input: &[[u8; 2]], out: &mut [[u8; 2]]) {
+    mul_slice_planes(c, input, out, false)
+}
+
+/// Multiply a slice of elements by `c`, adding each result to the
+/// corresponding value in `out`.
+///
+/// # Panics
+/// Panics if the output slice does not have equal length to the input.
+pub fn mul_slice_xor(c: [u8; 2], input: &[[u8; 2]], out: &mut [[u8; 2]]) {
+    mul_slice_planes(c, input, out, true)
+}
+
+fn mul_slice_planes(c: [u8; 2], input: &[[u8; 2]], out: &mut [[u8; 2]], add: bool) {
+    assert_eq!(input.len(), out.len());
+
+    if input.len() < PLANE_THRESHOLD {
+        let c = Gf65536(c);
+        for (i, o) in input.iter().zip(out.iter_mut()) {
+            let product = (c * Gf65536(*i)).0;
+            *o = if add {
+                (Gf65536(*o) + Gf65536(product)).0
+            } else {
+                product
+            };
+        }
+        return;
+    }
+
+    // (a1*x + a0) * (c1*x + c0), reduced with x^2 = EXT_POLY[1]*x + EXT_POLY[2]:
+    //
+    //   hi = a1 * (c0 + EXT_POLY[1]*c1) + a0 * c1
+    //   lo = a0 * c0                    + a1 * (EXT_POLY[2]*c1)
+    let [c1, c0] = c;
+    let hi_from_hi = galois_8::add(c0, galois_8::mul(EXT_POLY[1], c1));
+    let lo_from_hi = galois_8::mul(EXT_POLY[2], c1);
+
+    let mut in_hi = [0u8; PLANE_LEN];
+    let mut in_lo = [0u8; PLANE_LEN];
+    let mut out_hi = [0u8; PLANE_LEN];
+    let mut out_lo = [0u8; PLANE_LEN];
+
+    for (input, out) in input.chunks(PLANE_LEN).zip(out.chunks_mut(PLANE_LEN)) {
+        let len = input.len();
+        let in_hi = &mut in_hi[..len];
+        let in_lo = &mut in_lo[..len];
+        let out_hi = &mut out_hi[..len];
+        let out_lo = &mut out_lo[..len];
+
+        // Going through `u16` lets the compiler vectorise the (de)interleaving.
+        for ((h, l), e) in in_hi.iter_mut().zip(in_lo.iter_mut()).zip(input) {
+            let w = u16::from_le_bytes(*e);
+            *h = w as u8;
+            *l = (w >> 8) as u8;
+        }
+
+        galois_8::mul_slice(hi_from_hi, in_hi, out_hi);
+        galois_8::mul_slice_xor(c1, in_lo, out_hi);
+        galois_8::mul_slice(c0, in_lo, out_lo);
+        galois_8::mul_slice_xor(lo_from_hi, in_hi, out_lo);
+
+        let planes = out_hi.iter().zip(out_lo.iter()).zip(out);
+        if add {
+            for ((h, l), o) in planes {
+                let w = u16::from_le_bytes(*o) ^ (*h as u16 | (*l as u16) << 8);
+                *o = w.to_le_bytes();
+            }
+        } else {
+            for ((h, l), o) in planes {
+                *o = (*h as u16 | (*l as u16) << 8).to_le_bytes();
+            }
+        }
+    }
+}
+
+/// An element of `GF(2^16)`, with the arithmetic of `Field`.
+///
+/// The first byte is the high-order coefficient, as for the symbols of `Field`.
+///
+/// ```
+/// use reed_solomon_erasure::galois_16::Gf65536;
+///
+/// let a = Gf65536::from(0x1234);
+/// let b = Gf65536::from(0xABCD);
+/// assert_eq!((a * b) / b, a);
+/// assert_eq!(a.pow(2), a * a);
+/// assert_eq!(Gf65536::antilog(a.log().unwrap()), a);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Gf65536(pub [u8; 2]);
+
+impl Gf65536 {
+    /// The additive identity.
+    pub const ZERO: Gf65536 = Gf65536([0, 0]);
+
+    /// The multiplicative identity.
+    pub const ONE: Gf65536 = Gf65536([0, 1]);
+
+    /// The generator of the multiplicative group used for `log` and `antilog`,
+    /// the root `x` of the polynomial defining the extension.
+    pub const GENERATOR: Gf65536 = Gf65536([1, 0]);
+
+    /// The multiplicative inverse.
+    ///
+    /// # Panics
+    /// Panics if the element is zero.
+    pub fn inv(self) -> Gf65536 {
+        self.inverse()
+    }
+
+    /// The logarithm to the base `GENERATOR`, or `None` for zero.
+    pub fn log(self) -> Option<usize> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(tables().log[self.to_index()] as usize)
+        }
+    }
+
+    /// `GENERATOR` raised to the power `n`.
+    pub fn antilog(n: usize) -> Gf65536 {
+        Gf65536::from_index(tables().exp[n % GROUP_ORDER])
+    }
+
+    /// Iterate over all elements, in increasing order of their `u16` value.
+    pub fn all() -> impl Iterator<Item = Gf65536> {
+        (0..=u16::MAX).map(Gf65536::from_index)
+    }
 
-impl Element {
     // Create the zero element.
     fn zero() -> Self {
-        Element([0, 0])
+        Gf65536([0, 0])
     }
 
     // A constant element evaluating to `n`.
-    fn constant(n: u8) -> Element {
-        Element([0, n])
+    fn constant(n: u8) -> Gf65536 {
+        Gf65536([0, n])
     }
 
     // Whether this is the zero element.
@@ -77,19 +496,42 @@ impl Element {
         self.0 == [0; 2]
     }
 
-    fn exp(mut self, n: usize) -> Element {
+    // The element as a 16-bit integer, matching `nth_internal`.
+    fn to_index(self) -> usize {
+        u16::from_be_bytes(self.0) as usize
+    }
+
+    fn from_index(n: u16) -> Gf65536 {
+        Gf65536(n.to_be_bytes())
+    }
+
+    /// Raise the element to the power `n`.
+    pub fn pow(self, n: usize) -> Gf65536 {
         if n == 0 {
-            Element::constant(1)
-        } else if self == Element::zero() {
-            Element::zero()
+            Gf65536::constant(1)
+        } else if self.is_zero() {
+            Gf65536::zero()
         } else {
-            let x = self;
-            for _ in 1..n {
-                self = self * x;
-            }
+            let tables = tables();
+            let log = tables.log[self.to_index()] as u64;
+            let log_result = log * (n % GROUP_ORDER) as u64 % GROUP_ORDER as u64;
+            Gf65536::from_index(tables.exp[log_result as usize])
+        }
+    }
 
-            self
+    // Square-and-multiply without the tables, to check them against.
+    #[cfg(test)]
+    fn pow_slow(self, mut n: usize) -> Gf65536 {
+        let mut result = Gf65536::constant(1);
+        let mut base = self;
+        while n > 0 {
+            if n & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            n >>= 1;
         }
+        result
     }
 
     // reduces from some polynomial with degree <= 2.
@@ -103,50 +545,63 @@ impl Element {
             x[2] ^= galois_8::mul(EXT_POLY[2], x[0]);
         }
 
-        Element([x[1], x[2]])
+        Gf65536([x[1], x[2]])
     }
 
-    fn degree(&self) -> usize {
-        if self.0[0] != 0 {
-            1
-        } else {
-            0
-        }
-    }
 }
 
-impl From<[u8; 2]> for Element {
+impl From<[u8; 2]> for Gf65536 {
     fn from(c: [u8; 2]) -> Self {
-        Element(c)
+        Gf65536(c)
+    }
+}
+
+impl From<u16> for Gf65536 {
+    fn from(n: u16) -> Self {
+        Gf65536::from_index(n)
+    }
+}
+
+impl From<Gf65536> for u16 {
+    fn from(a: Gf65536) -> u16 {
+        u16::from_be_bytes(a.0)
     }
 }
 
-impl Default for Element {
+impl Default for Gf65536 {
     fn default() -> Self {
-        Element::zero()
+        Gf65536::zero()
     }
 }
 
-impl Add for Element {
-    type Output = Element;
+impl Add for Gf65536 {
+    type Output = Gf65536;
 
-    fn add(self, other: Self) -> Element {
-        Element([self.0[0] ^ other.0[0], self.0[1] ^ other.0[1]])
+    fn add(self, other: Self) -> Gf65536 {
+        Gf65536([self.0[0] ^ other.0[0], self.0[1] ^ other.0[1]])
     }
 }
 
-impl Sub for Element {
-    type Output = Element;
+impl Sub for Gf65536 {
+    type Output = Gf65536;
 
-    fn sub(self, other: Self) -> Element {
+    fn sub(self, other: Self) -> Gf65536 {
         self.add(other)
     }
 }
 
-impl Mul for Element {
-    type Output = Element;
+impl Neg for Gf65536 {
+    type Output = Gf65536;
+
+    fn neg(self) -> Gf65536 {
+        self
+    }
+}
+
+impl Mul for Gf65536 {
+    type Output = Gf65536;
 
-    fn mul(self, rhs: Self) -> Element {
+    fn mul(self, rhs: Self) -> Gf65536 {
         // FOIL; our elements are linear at most, with two coefficients
         let out: [u8; 3] = [
             galois_8::mul(self.0[0], rhs.0[0]),
@@ -157,256 +612,262 @@ impl Mul for Element {
             galois_8::mul(self.0[1], rhs.0[1]),
         ];
 
-        Element::reduce_from(out)
+        Gf65536::reduce_from(out)
     }
 }
 
-impl Mul<u8> for Element {
-    type Output = Element;
+impl Mul<u8> for Gf65536 {
+    type Output = Gf65536;
 
-    fn mul(self, rhs: u8) -> Element {
-        Element([galois_8::mul(rhs, self.0[0]), galois_8::mul(rhs, self.0[1])])
+    fn mul(self, rhs: u8) -> Gf65536 {
+        Gf65536([galois_8::mul(rhs, self.0[0]), galois_8::mul(rhs, self.0[1])])
     }
 }
 
-impl Div for Element {
-    type Output = Element;
+impl Div for Gf65536 {
+    type Output = Gf65536;
 
-    fn div(self, rhs: Self) -> Element {
+    fn div(self, rhs: Self) -> Gf65536 {
         self * rhs.inverse()
     }
 }
 
-// helpers for division.
-
-#[derive(Debug)]
-enum EgcdRhs {
-    Element(Element),
-    ExtPoly,
+impl AddAssign for Gf65536 {
+    fn add_assign(&mut self, rhs: Gf65536) {
+        *self = *self + rhs;
+    }
 }
 
-impl Element {
-    // compute extended euclidean algorithm against an element of self,
-    // where the GCD is known to be constant.
-    fn const_egcd(self, rhs: EgcdRhs) -> (u8, Element, Element) {
-        if self.is_zero() {
-            let rhs = match rhs {
-                EgcdRhs::Element(elem) => elem,
-                EgcdRhs::ExtPoly => panic!("const_egcd invoked with divisible"),
-            };
-            (rhs.0[1], Element::constant(0), Element::constant(1))
-        } else {
-            let (cur_quotient, cur_remainder) = match rhs {
-                EgcdRhs::Element(rhs) => rhs.polynom_div(self),
-                EgcdRhs::ExtPoly => Element::div_ext_by(self),
-            };
-
-            // GCD is constant because EXT_POLY is irreducible
-            let (g, x, y) = cur_remainder.const_egcd(EgcdRhs::Element(self));
-            (g, y + (cur_quotient * x), x)
-        }
+impl SubAssign for Gf65536 {
+    fn sub_assign(&mut self, rhs: Gf65536) {
+        *self = *self - rhs;
     }
+}
 
-    // divide EXT_POLY by self.
-    fn div_ext_by(rhs: Self) -> (Element, Element) {
-        if rhs.degree() == 0 {
-            // dividing by constant is the same as multiplying by another constant.
-            // and all constant multiples of EXT_POLY are in the equivalence class
-            // of 0.
-            return (Element::zero(), Element::zero());
-        }
-
-        // divisor is ensured linear here.
-        // now ensure divisor is monic.
-        let leading_mul_inv = galois_8::div(1, rhs.0[0]);
+impl MulAssign for Gf65536 {
+    fn mul_assign(&mut self, rhs: Gf65536) {
+        *self = *self * rhs;
+    }
+}
 
-        let monictized = rhs * leading_mul_inv;
-        let mut poly = EXT_POLY;
+impl DivAssign for Gf65536 {
+    fn div_assign(&mut self, rhs: Gf65536) {
+        *self = *self / rhs;
+    }
+}
 
-        for i in 0..2 {
-            let coef = poly[i];
-            for j in 1..2 {
-                if rhs.0[j] != 0 {
-                    poly[i + j] ^= galois_8::mul(monictized.0[j], coef);
-                }
-            }
+impl Gf65536 {
+    /// Convert the inverse of this field element. Panics if zero.
+    fn inverse(self) -> Gf65536 {
+        if self.is_zero() {
+            panic!("Cannot invert 0");
         }
 
-        let remainder = Element::constant(poly[2]);
-        let quotient = Element([poly[0], poly[1]]) * leading_mul_inv;
-
-        (quotient, remainder)
+        let tables = tables();
+        let log = tables.log[self.to_index()] as usize;
+        Gf65536::from_index(tables.exp[GROUP_ORDER - log])
     }
+}
 
-    fn polynom_div(self, rhs: Self) -> (Element, Element) {
-        let divisor_degree = rhs.degree();
-        if rhs.is_zero() {
-            panic!("divide by 0");
-        } else if self.degree() < divisor_degree {
-            // If divisor's degree (len-1) is bigger, all dividend is a remainder
-            (Element::zero(), self)
-        } else if divisor_degree == 0 {
-            // divide by constant.
-            let invert = galois_8::div(1, rhs.0[1]);
-            let quotient = Element([
-                galois_8::mul(invert, self.0[0]),
-                galois_8::mul(invert, self.0[1]),
-            ]);
+// Order of the multiplicative group of the field.
+const GROUP_ORDER: usize = 65535;
 
-            (quotient, Element::zero())
-        } else {
-            // self degree is at least divisor degree, divisor degree not 0.
-            // therefore both are 1.
-            debug_assert_eq!(self.degree(), divisor_degree);
-            debug_assert_eq!(self.degree(), 1);
-
-            // ensure rhs is constant.
-            let leading_mul_inv = galois_8::div(1, rhs.0[0]);
-            let monic = Element([
-                galois_8::mul(leading_mul_inv, rhs.0[0]),
-                galois_8::mul(leading_mul_inv, rhs.0[1]),
-            ]);
-
-            let leading_coeff = self.0[0];
-            let mut remainder = self.0[1];
-
-            if monic.0[1] != 0 {
-                remainder ^= galois_8::mul(monic.0[1], self.0[0]);
-            }
+// Distinct prime factors of `GROUP_ORDER`, used to test for a generator.
+#[cfg(test)]
+const GROUP_ORDER_FACTORS: [usize; 4] = [3, 5, 17, 257];
 
-            (
-                Element::constant(galois_8::mul(leading_mul_inv, leading_coeff)),
-                Element::constant(remainder),
-            )
-        }
-    }
+// Log and antilog tables over a generator of the multiplicative group.
+//
+// `exp` is doubled in length so a sum of two logs can be looked up
+// without reduction.
+struct Tables {
+    log: Vec<u16>,
+    exp: Vec<u16>,
+}
 
-    /// Convert the inverse of this field element. Panics if zero.
-    fn inverse(self) -> Element {
-        if self.is_zero() {
-            panic!("Cannot invert 0");
-        }
+// Built on first use, the tables take 384 KiB which we do not want to pay
+// for in every binary that links the crate.
+static TABLES: Once<Tables> = Once::new();
 
-        // first step of extended euclidean algorithm.
-        // done here because EXT_POLY is outside the scope of `Element`.
-        let (gcd, y) = {
-            // self / EXT_POLY = (0, self)
-            let remainder = self;
+fn tables() -> &'static Tables {
+    TABLES.call_once(build_tables)
+}
 
-            // GCD is constant because EXT_POLY is irreducible
-            let (g, x, _) = remainder.const_egcd(EgcdRhs::ExtPoly);
+fn build_tables() -> Tables {
+    let mut log = vec![0; GROUP_ORDER + 1];
+    let mut exp = vec![0; GROUP_ORDER * 2];
 
-            (g, x)
-        };
+    let mut x = Gf65536::constant(1);
+    for i in 0..GROUP_ORDER {
+        let n = x.to_index() as u16;
+        log[n as usize] = i as u16;
+        exp[i] = n;
+        exp[i + GROUP_ORDER] = n;
 
-        // we still need to normalize it by dividing by the gcd
-        if gcd != 0 {
-            // EXT_POLY is irreducible so the GCD will always be constant.
-            // EXT_POLY*x + self*y = gcd
-            // self*y = gcd - EXT_POLY*x
-            //
-            // EXT_POLY*x is representative of the equivalence class of 0.
-            let normalizer = galois_8::div(1, gcd);
-            y * normalizer
-        } else {
-            // self is equivalent to zero.
-            panic!("Cannot invert 0");
-        }
+        x *= Gf65536::GENERATOR;
     }
+
+    Tables { log, exp }
 }
 
 #[cfg(test)]
 mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
     use super::*;
     use quickcheck::Arbitrary;
 
-    impl Arbitrary for Element {
+    impl Arbitrary for Gf65536 {
         fn arbitrary<G: quickcheck::Gen>(gen: &mut G) -> Self {
             let a = u8::arbitrary(gen);
             let b = u8::arbitrary(gen);
 
-            Element([a, b])
+            Gf65536([a, b])
         }
     }
 
     quickcheck! {
-        fn qc_add_associativity(a: Element, b: Element, c: Element) -> bool {
+        fn qc_add_associativity(a: Gf65536, b: Gf65536, c: Gf65536) -> bool {
             a + (b + c) == (a + b) + c
         }
 
-        fn qc_mul_associativity(a: Element, b: Element, c: Element) -> bool {
+        fn qc_mul_associativity(a: Gf65536, b: Gf65536, c: Gf65536) -> bool {
             a * (b * c) == (a * b) * c
         }
 
-        fn qc_additive_identity(a: Element) -> bool {
-            let zero = Element::zero();
+        fn qc_additive_identity(a: Gf65536) -> bool {
+            let zero = Gf65536::zero();
             a - (zero - a) == zero
         }
 
-        fn qc_multiplicative_identity(a: Element) -> bool {
+        fn qc_multiplicative_identity(a: Gf65536) -> bool {
             a.is_zero() || {
-                let one = Element([0, 1]);
+                let one = Gf65536([0, 1]);
                 (one / a) * a == one
             }
         }
 
-        fn qc_add_commutativity(a: Element, b: Element) -> bool {
+        fn qc_add_commutativity(a: Gf65536, b: Gf65536) -> bool {
             a + b == b + a
         }
 
-        fn qc_mul_commutativity(a: Element, b: Element) -> bool {
+        fn qc_mul_commutativity(a: Gf65536, b: Gf65536) -> bool {
             a * b == b * a
         }
 
-        fn qc_add_distributivity(a: Element, b: Element, c: Element) -> bool {
+        fn qc_add_distributivity(a: Gf65536, b: Gf65536, c: Gf65536) -> bool {
             a * (b + c) == (a * b) + (a * c)
         }
 
-        fn qc_inverse(a: Element) -> bool {
+        fn qc_inverse(a: Gf65536) -> bool {
             a.is_zero() || {
                 let inv = a.inverse();
-                a * inv == Element::constant(1)
+                a * inv == Gf65536::constant(1)
             }
         }
 
-        fn qc_exponent_1(a: Element, n: u8) -> bool {
+        fn qc_exponent_1(a: Gf65536, n: u8) -> bool {
             a.is_zero() || n == 0 || {
-                let mut b = a.exp(n as usize);
+                let mut b = a.pow(n as usize);
                 for _ in 1..n {
-                    b = b / a;
+                    b /= a;
                 }
 
                 a == b
             }
         }
 
-        fn qc_exponent_2(a: Element, n: u8) -> bool {
+        fn qc_exponent_2(a: Gf65536, n: u8) -> bool {
             a.is_zero() || {
                 let mut res = true;
-                let mut b = Element::constant(1);
+                let mut b = Gf65536::constant(1);
 
                 for i in 0..n {
-                    res = res && b == a.exp(i as usize);
-                    b = b * a;
+                    res = res && b == a.pow(i as usize);
+                    b *= a;
                 }
 
                 res
             }
         }
 
-        fn qc_exp_zero_is_one(a: Element) -> bool {
-            a.exp(0) == Element::constant(1)
+        fn qc_exp_same_as_pow_slow(a: Gf65536, n: usize) -> bool {
+            a.pow(n) == a.pow_slow(n)
+        }
+
+        fn qc_exp_zero_is_one(a: Gf65536) -> bool {
+            a.pow(0) == Gf65536::constant(1)
         }
     }
 
+    #[test]
+    fn test_mul_slice_same_as_scalar_mul() {
+        let lens = [
+            0,
+            1,
+            7,
+            PLANE_THRESHOLD - 1,
+            PLANE_THRESHOLD,
+            PLANE_LEN - 1,
+            PLANE_LEN,
+            PLANE_LEN + 3,
+            1000,
+        ];
+        for &len in lens.iter() {
+            let c = Gf65536(rand::random());
+            let input: Vec<[u8; 2]> = (0..len).map(|_| rand::random()).collect();
+            let orig: Vec<[u8; 2]> = (0..len).map(|_| rand::random()).collect();
+
+            let mut output = orig.clone();
+            mul_slice(c.0, &input, &mut output);
+            for i in 0..len {
+                assert_eq!((c * Gf65536(input[i])).0, output[i]);
+            }
+
+            let mut output = orig.clone();
+            mul_slice_xor(c.0, &input, &mut output);
+            for i in 0..len {
+                assert_eq!((Gf65536(orig[i]) + c * Gf65536(input[i])).0, output[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_log_exp_tables() {
+        let tables = tables();
+        for n in 1..=GROUP_ORDER {
+            let log = tables.log[n] as usize;
+            assert_eq!(tables.exp[log] as usize, n);
+            assert_eq!(tables.exp[log + GROUP_ORDER] as usize, n);
+        }
+    }
+
+    #[test]
+    fn test_log_antilog() {
+        assert_eq!(None, Gf65536::ZERO.log());
+        assert_eq!(Gf65536::ONE, Gf65536::antilog(GROUP_ORDER));
+        for a in Gf65536::all().skip(1) {
+            assert_eq!(a, Gf65536::antilog(a.log().unwrap()));
+            assert_eq!(a, Gf65536::from(u16::from(a)));
+        }
+        assert_eq!(GROUP_ORDER + 1, Gf65536::all().count());
+
+        assert_eq!(Gf65536::GENERATOR, Gf65536::antilog(1));
+        assert!(GROUP_ORDER_FACTORS
+            .iter()
+            .all(|p| Gf65536::GENERATOR.pow_slow(GROUP_ORDER / p) != Gf65536::ONE));
+    }
+
     #[test]
     #[should_panic]
     fn test_div_b_is_0() {
-        let _ = Element([1, 0]) / Element::zero();
+        let _ = Gf65536([1, 0]) / Gf65536::zero();
     }
 
     #[test]
     fn zero_to_zero_is_one() {
-        assert_eq!(Element::zero().exp(0), Element::constant(1))
+        assert_eq!(Gf65536::zero().pow(0), Gf65536::constant(1))
     }
 }
@@ -0,0 +1,314 @@
+//! Implementation of the binary field GF(2^16) over the polynomial
+//! x^16 + x^12 + x^3 + x + 1 (0x1100B).
+//!
+//! This is the field polynomial used by PAR2, unlike `galois_16` which is an
+//! extension field of `GF(2^8)`. Symbols are `u16` values, with the generator
+//! 2 (i.e. x).
+//!
+//! Note that only the field polynomial is shared with PAR2, `ReedSolomon`
+//! still builds its own coding matrix.
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use spin::Once;
+
+// x^16 + x^12 + x^3 + x + 1
+const GENERATING_POLYNOMIAL: u32 = 0x1100B;
+
+// Order of the multiplicative group of the field.
+const GROUP_ORDER: usize = 65535;
+
+/// The field GF(2^16) over 0x1100B.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Field;
+
+impl crate::Field for Field {
+    const ORDER: usize = 65536;
+    type Elem = u16;
+
+    fn add(a: u16, b: u16) -> u16 {
+        add(a, b)
+    }
+
+    fn mul(a: u16, b: u16) -> u16 {
+        mul(a, b)
+    }
+
+    fn div(a: u16, b: u16) -> u16 {
+        div(a, b)
+    }
+
+    fn exp(elem: u16, n: usize) -> u16 {
+        exp(elem, n)
+    }
+
+    fn zero() -> u16 {
+        0
+    }
+
+    fn one() -> u16 {
+        1
+    }
+
+    fn nth_internal(n: usize) -> u16 {
+        n as u16
+    }
+
+    fn mul_slice(c: u16, input: &[u16], out: &mut [u16]) {
+        mul_slice(c, input, out)
+    }
+
+    fn mul_slice_add(c: u16, input: &[u16], out: &mut [u16]) {
+        mul_slice_xor(c, input, out)
+    }
+}
+
+/// Type alias of ReedSolomon over GF(2^16) with polynomial 0x1100B.
+pub type ReedSolomon = crate::ReedSolomon<Field>;
+
+/// Type alias of ShardByShard over GF(2^16) with polynomial 0x1100B.
+pub type ShardByShard<'a> = crate::ShardByShard<'a, Field>;
+
+// Log and antilog tables, see `galois_16` for the layout.
+struct Tables {
+    log: Vec<u16>,
+    exp: Vec<u16>,
+}
+
+static TABLES: Once<Tables> = Once::new();
+
+fn tables() -> &'static Tables {
+    TABLES.call_once(build_tables)
+}
+
+fn build_tables() -> Tables {
+    let mut log = vec![0; GROUP_ORDER + 1];
+    let mut exp = vec![0; GROUP_ORDER * 2];
+
+    let mut b: u32 = 1;
+    for i in 0..GROUP_ORDER {
+        log[b as usize] = i as u16;
+        exp[i] = b as u16;
+        exp[i + GROUP_ORDER] = b as u16;
+
+        b <<= 1;
+        if b > 0xFFFF {
+            b ^= GENERATING_POLYNOMIAL;
+        }
+    }
+
+    Tables { log, exp }
+}
+
+// Multiply by x, i.e. the generator.
+fn mul_by_x(a: u16) -> u16 {
+    let b = (a as u32) << 1;
+    if b > 0xFFFF {
+        (b ^ GENERATING_POLYNOMIAL) as u16
+    } else {
+        b as u16
+    }
+}
+
+/// Add two elements.
+pub fn add(a: u16, b: u16) -> u16 {
+    a ^ b
+}
+
+/// Multiply two elements.
+pub fn mul(a: u16, b: u16) -> u16 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        let tables = tables();
+        let log_a = tables.log[a as usize] as usize;
+        let log_b = tables.log[b as usize] as usize;
+        tables.exp[log_a + log_b]
+    }
+}
+
+/// Divide one element by another. `b`, the divisor, may not be 0.
+pub fn div(a: u16, b: u16) -> u16 {
+    if a == 0 {
+        0
+    } else if b == 0 {
+        panic!("Divisor is 0")
+    } else {
+        let tables = tables();
+        let log_a = tables.log[a as usize] as usize;
+        let log_b = tables.log[b as usize] as usize;
+        tables.exp[log_a + GROUP_ORDER - log_b]
+    }
+}
+
+/// Compute a^n.
+pub fn exp(a: u16, n: usize) -> u16 {
+    if n == 0 {
+        1
+    } else if a == 0 {
+        0
+    } else {
+        let tables = tables();
+        let log_a = tables.log[a as usize] as u64;
+        let log_result = log_a * (n % GROUP_ORDER) as u64 % GROUP_ORDER as u64;
+        tables.exp[log_result as usize]
+    }
+}
+
+// Products of `c` with every value of the low and the high byte of a symbol.
+//
+// Multiplication by a constant is linear over GF(2), so the tables are filled
+// from the products with the 16 basis elements, without any table lookups.
+fn byte_tables(c: u16) -> ([u16; 256], [u16; 256]) {
+    let mut basis = [0u16; 16];
+    let mut b = c;
+    for x in basis.iter_mut() {
+        *x = b;
+        b = mul_by_x(b);
+    }
+
+    let mut low = [0u16; 256];
+    let mut high = [0u16; 256];
+    for i in 1..256usize {
+        let bit = i.trailing_zeros() as usize;
+        low[i] = low[i & (i - 1)] ^ basis[bit];
+        high[i] = high[i & (i - 1)] ^ basis[bit + 8];
+    }
+
+    (low, high)
+}
+
+/// Multiply a slice of elements by `c`. Writes into the output slice.
+///
+/// # Panics
+/// Panics if the output slice does not have equal length to the input.
+pub fn mul_slice(c: u16, input: &[u16], out: &mut [u16]) {
+    assert_eq!(input.len(), out.len());
+
+    let (low, high) = byte_tables(c);
+    for (i, o) in input.iter().zip(out.iter_mut()) {
+        *o = low[(*i & 0xFF) as usize] ^ high[(*i >> 8) as usize];
+    }
+}
+
+/// Multiply a slice of elements by `c`, adding each result to the
+/// corresponding value in `out`.
+///
+/// # Panics
+/// Panics if the output slice does not have equal length to the input.
+pub fn mul_slice_xor(c: u16, input: &[u16], out: &mut [u16]) {
+    assert_eq!(input.len(), out.len());
+
+    let (low, high) = byte_tables(c);
+    for (i, o) in input.iter().zip(out.iter_mut()) {
+        *o ^= low[(*i & 0xFF) as usize] ^ high[(*i >> 8) as usize];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::tests::fill_random;
+
+    quickcheck! {
+        fn qc_add_associativity(a: u16, b: u16, c: u16) -> bool {
+            add(a, add(b, c)) == add(add(a, b), c)
+        }
+
+        fn qc_mul_associativity(a: u16, b: u16, c: u16) -> bool {
+            mul(a, mul(b, c)) == mul(mul(a, b), c)
+        }
+
+        fn qc_multiplicative_identity(a: u16) -> bool {
+            a == 0 || mul(a, div(1, a)) == 1
+        }
+
+        fn qc_mul_commutativity(a: u16, b: u16) -> bool {
+            mul(a, b) == mul(b, a)
+        }
+
+        fn qc_add_distributivity(a: u16, b: u16, c: u16) -> bool {
+            mul(a, add(b, c)) == add(mul(a, b), mul(a, c))
+        }
+
+        fn qc_exponent(a: u16, n: u8) -> bool {
+            let mut b = 1;
+            for _ in 0..n {
+                b = mul(b, a);
+            }
+
+            exp(a, n as usize) == b
+        }
+    }
+
+    #[test]
+    fn test_known_values() {
+        // x^16 = x^12 + x^3 + x + 1
+        assert_eq!(mul(0x8000, 2), 0x100B);
+        assert_eq!(exp(2, 16), 0x100B);
+        assert_eq!(exp(2, GROUP_ORDER), 1);
+        assert_eq!(mul(0x1234, 0x5678), 0x6324);
+    }
+
+    #[test]
+    fn test_generator_is_primitive() {
+        let tables = tables();
+        let mut seen = vec![false; GROUP_ORDER + 1];
+        for &x in tables.exp[..GROUP_ORDER].iter() {
+            assert!(!seen[x as usize]);
+            seen[x as usize] = true;
+        }
+    }
+
+    #[test]
+    fn test_mul_slice_same_as_scalar_mul() {
+        for &len in [0, 1, 7, 1000].iter() {
+            let c = rand::random::<u16>();
+            let mut input: Vec<u16> = vec![0; len];
+            fill_random(&mut input);
+            let mut orig: Vec<u16> = vec![0; len];
+            fill_random(&mut orig);
+
+            let mut output = orig.clone();
+            mul_slice(c, &input, &mut output);
+            for i in 0..len {
+                assert_eq!(mul(c, input[i]), output[i]);
+            }
+
+            let mut output = orig.clone();
+            mul_slice_xor(c, &input, &mut output);
+            for i in 0..len {
+                assert_eq!(orig[i] ^ mul(c, input[i]), output[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_reconstruct() {
+        let r = ReedSolomon::new(5, 3).unwrap();
+
+        let mut expect: Vec<Vec<u16>> = vec![vec![0; 100]; 8];
+        for s in expect[..5].iter_mut() {
+            fill_random(s);
+        }
+        r.encode(&mut expect).unwrap();
+        assert!(r.verify(&expect).unwrap());
+
+        let mut shards: Vec<_> = expect.iter().cloned().map(Some).collect();
+        shards[0] = None;
+        shards[3] = None;
+        shards[7] = None;
+        r.reconstruct(&mut shards).unwrap();
+
+        let shards: Vec<_> = shards.into_iter().map(Option::unwrap).collect();
+        assert_eq!(expect, shards);
+    }
+}
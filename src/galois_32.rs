@@ -0,0 +1,290 @@
+//! Implementation of GF(2^32): the finite field with 2^32 elements.
+//!
+//! This field allows for codecs with more than 65,536 shards in total.
+//!
+//! Elements are `u32` values, multiplied carry-less and reduced by
+//! x^32 + x^22 + x^2 + x + 1. The field is too large for log tables,
+//! so division and exponentiation are done by repeated squaring.
+
+// x^32 + x^22 + x^2 + x + 1, without the x^32 term.
+const GENERATING_POLYNOMIAL: u32 = 0x0040_0007;
+
+/// The field GF(2^32).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Field;
+
+impl crate::Field for Field {
+    // Saturates on targets where `usize` can not hold 2^32.
+    const ORDER: usize = if usize::BITS > 32 {
+        (1u64 << 32) as usize
+    } else {
+        usize::MAX
+    };
+
+    type Elem = u32;
+
+    fn add(a: u32, b: u32) -> u32 {
+        add(a, b)
+    }
+
+    fn mul(a: u32, b: u32) -> u32 {
+        mul(a, b)
+    }
+
+    fn div(a: u32, b: u32) -> u32 {
+        div(a, b)
+    }
+
+    fn exp(elem: u32, n: usize) -> u32 {
+        exp(elem, n)
+    }
+
+    fn zero() -> u32 {
+        0
+    }
+
+    fn one() -> u32 {
+        1
+    }
+
+    fn nth_internal(n: usize) -> u32 {
+        n as u32
+    }
+
+    fn mul_slice(c: u32, input: &[u32], out: &mut [u32]) {
+        mul_slice(c, input, out)
+    }
+
+    fn mul_slice_add(c: u32, input: &[u32], out: &mut [u32]) {
+        mul_slice_xor(c, input, out)
+    }
+}
+
+/// Type alias of ReedSolomon over GF(2^32).
+pub type ReedSolomon = crate::ReedSolomon<Field>;
+
+/// Type alias of ShardByShard over GF(2^32).
+pub type ShardByShard<'a> = crate::ShardByShard<'a, Field>;
+
+// Multiply by x.
+fn mul_by_x(a: u32) -> u32 {
+    if a & 0x8000_0000 != 0 {
+        (a << 1) ^ GENERATING_POLYNOMIAL
+    } else {
+        a << 1
+    }
+}
+
+/// Add two elements.
+pub fn add(a: u32, b: u32) -> u32 {
+    a ^ b
+}
+
+/// Multiply two elements.
+pub fn mul(mut a: u32, mut b: u32) -> u32 {
+    let mut result = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            result ^= a;
+        }
+        a = mul_by_x(a);
+        b >>= 1;
+    }
+    result
+}
+
+/// Compute a^n.
+pub fn exp(mut a: u32, mut n: usize) -> u32 {
+    let mut result = 1;
+    while n > 0 {
+        if n & 1 == 1 {
+            result = mul(result, a);
+        }
+        a = mul(a, a);
+        n >>= 1;
+    }
+    result
+}
+
+/// Compute the multiplicative inverse of `a`, which may not be 0.
+pub fn inverse(a: u32) -> u32 {
+    if a == 0 {
+        panic!("Cannot invert 0");
+    }
+
+    // a^(2^32 - 1) = 1
+    exp(a, (u32::MAX - 1) as usize)
+}
+
+/// Divide one element by another. `b`, the divisor, may not be 0.
+pub fn div(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        panic!("Divisor is 0")
+    } else if a == 0 {
+        0
+    } else {
+        mul(a, inverse(b))
+    }
+}
+
+// Products of `c` with every value of each byte of an element.
+//
+// Multiplication by a constant is linear over GF(2), so the tables are filled
+// from the products with the 32 basis elements.
+fn byte_tables(c: u32) -> [[u32; 256]; 4] {
+    let mut basis = [0u32; 32];
+    let mut b = c;
+    for x in basis.iter_mut() {
+        *x = b;
+        b = mul_by_x(b);
+    }
+
+    let mut tables = [[0u32; 256]; 4];
+    for (k, table) in tables.iter_mut().enumerate() {
+        for i in 1..256usize {
+            let bit = i.trailing_zeros() as usize;
+            table[i] = table[i & (i - 1)] ^ basis[k * 8 + bit];
+        }
+    }
+
+    tables
+}
+
+/// Multiply a slice of elements by `c`. Writes into the output slice.
+///
+/// # Panics
+/// Panics if the output slice does not have equal length to the input.
+pub fn mul_slice(c: u32, input: &[u32], out: &mut [u32]) {
+    assert_eq!(input.len(), out.len());
+
+    let t = byte_tables(c);
+    for (i, o) in input.iter().zip(out.iter_mut()) {
+        let [b0, b1, b2, b3] = i.to_le_bytes();
+        *o = t[0][b0 as usize] ^ t[1][b1 as usize] ^ t[2][b2 as usize] ^ t[3][b3 as usize];
+    }
+}
+
+/// Multiply a slice of elements by `c`, adding each result to the
+/// corresponding value in `out`.
+///
+/// # Panics
+/// Panics if the output slice does not have equal length to the input.
+pub fn mul_slice_xor(c: u32, input: &[u32], out: &mut [u32]) {
+    assert_eq!(input.len(), out.len());
+
+    let t = byte_tables(c);
+    for (i, o) in input.iter().zip(out.iter_mut()) {
+        let [b0, b1, b2, b3] = i.to_le_bytes();
+        *o ^= t[0][b0 as usize] ^ t[1][b1 as usize] ^ t[2][b2 as usize] ^ t[3][b3 as usize];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::tests::fill_random;
+
+    quickcheck! {
+        fn qc_add_associativity(a: u32, b: u32, c: u32) -> bool {
+            add(a, add(b, c)) == add(add(a, b), c)
+        }
+
+        fn qc_mul_associativity(a: u32, b: u32, c: u32) -> bool {
+            mul(a, mul(b, c)) == mul(mul(a, b), c)
+        }
+
+        fn qc_multiplicative_identity(a: u32) -> bool {
+            a == 0 || mul(a, div(1, a)) == 1
+        }
+
+        fn qc_mul_commutativity(a: u32, b: u32) -> bool {
+            mul(a, b) == mul(b, a)
+        }
+
+        fn qc_add_distributivity(a: u32, b: u32, c: u32) -> bool {
+            mul(a, add(b, c)) == add(mul(a, b), mul(a, c))
+        }
+
+        fn qc_exponent(a: u32, n: u8) -> bool {
+            let mut b = 1;
+            for _ in 0..n {
+                b = mul(b, a);
+            }
+
+            exp(a, n as usize) == b
+        }
+    }
+
+    #[test]
+    fn test_known_values() {
+        // x^32 = x^22 + x^2 + x + 1
+        assert_eq!(mul(0x8000_0000, 2), 0x0040_0007);
+        assert_eq!(mul(0x1234_5678, 0x9ABC_DEF0), 0x808E_945D);
+        // x is a generator
+        assert_eq!(exp(2, u32::MAX as usize), 1);
+        assert_ne!(exp(2, (u32::MAX / 3) as usize), 1);
+        assert_ne!(exp(2, (u32::MAX / 65537) as usize), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_b_is_0() {
+        div(1, 0);
+    }
+
+    #[test]
+    fn test_mul_slice_same_as_scalar_mul() {
+        for &len in [0, 1, 7, 1000].iter() {
+            let c = rand::random::<u32>();
+            let mut input: Vec<u32> = vec![0; len];
+            fill_random(&mut input);
+            let mut orig: Vec<u32> = vec![0; len];
+            fill_random(&mut orig);
+
+            let mut output = orig.clone();
+            mul_slice(c, &input, &mut output);
+            for i in 0..len {
+                assert_eq!(mul(c, input[i]), output[i]);
+            }
+
+            let mut output = orig.clone();
+            mul_slice_xor(c, &input, &mut output);
+            for i in 0..len {
+                assert_eq!(orig[i] ^ mul(c, input[i]), output[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_reconstruct() {
+        let r = ReedSolomon::new(5, 3).unwrap();
+
+        let mut expect: Vec<Vec<u32>> = vec![vec![0; 100]; 8];
+        for s in expect[..5].iter_mut() {
+            fill_random(s);
+        }
+        r.encode(&mut expect).unwrap();
+        assert!(r.verify(&expect).unwrap());
+
+        let mut shards: Vec<_> = expect.iter().cloned().map(Some).collect();
+        shards[1] = None;
+        shards[4] = None;
+        shards[6] = None;
+        r.reconstruct(&mut shards).unwrap();
+
+        let shards: Vec<_> = shards.into_iter().map(Option::unwrap).collect();
+        assert_eq!(expect, shards);
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_more_than_65536_shards() {
+        let r = ReedSolomon::new(2, 70_000).unwrap();
+        assert_eq!(r.total_shard_count(), 70_002);
+    }
+}
@@ -0,0 +1,439 @@
+//! Implementation of GF(2^4): the finite field with 2^4 elements.
+//!
+//! Meant for small codecs (at most 16 shards in total) on constrained targets,
+//! all tables take up less than 300 bytes.
+//!
+//! # Packed symbols
+//!
+//! Every byte holds two symbols, one in the low and one in the high nibble.
+//! All operations act on both nibbles independently, so a shard of bytes is
+//! effectively two interleaved shards of nibbles which are coded in parallel.
+//!
+//! The packed bytes are not a field themselves: they are a product of two
+//! fields, in which e.g. `0x10` is nonzero yet has no inverse. So this module
+//! does not provide a `Field` to plug into `crate::ReedSolomon`, but its own
+//! byte-level `ReedSolomon`. All entries of its coding and decode matrices
+//! have the same symbol in both nibbles, e.g. `0x11` for one. Such bytes form
+//! a field isomorphic to GF(2^4), so the matrices are built and inverted
+//! exactly as over GF(2^4).
+//!
+//! The free functions act on pairs of symbols the same way.
+
+use smallvec::SmallVec;
+
+use crate::errors::Error;
+use crate::galois_8;
+use crate::{ReconstructShard, ShardState};
+
+// x^4 + x + 1
+const GENERATING_POLYNOMIAL: u8 = 0x13;
+
+// Products of all pairs of symbols, generated at compile time.
+static MUL_TABLE: [[u8; 16]; 16] = gen_mul_table();
+
+// Multiplicative inverses, with 0 mapped to 0.
+static INV_TABLE: [u8; 16] = gen_inv_table();
+
+const fn mul_nibble(mut a: u8, mut b: u8) -> u8 {
+    let mut result = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            result ^= a;
+        }
+        b >>= 1;
+        a <<= 1;
+        if a & 0x10 != 0 {
+            a ^= GENERATING_POLYNOMIAL;
+        }
+    }
+    result
+}
+
+const fn gen_mul_table() -> [[u8; 16]; 16] {
+    let mut table = [[0; 16]; 16];
+    let mut a = 0;
+    while a < 16 {
+        let mut b = 0;
+        while b < 16 {
+            table[a][b] = mul_nibble(a as u8, b as u8);
+            b += 1;
+        }
+        a += 1;
+    }
+    table
+}
+
+const fn gen_inv_table() -> [u8; 16] {
+    let mut table = [0; 16];
+    let mut a = 1;
+    while a < 16 {
+        let mut b = 1;
+        while mul_nibble(a, b) != 1 {
+            b += 1;
+        }
+        table[a as usize] = b;
+        a += 1;
+    }
+    table
+}
+
+// The packed bytes do not form a field, e.g. `0x10` is nonzero but has no
+// inverse, so this only implements `Field` for the inner codec, whose
+// coefficients all have the same symbol in both nibbles. See the module
+// documentation.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+struct Packed;
+
+impl crate::Field for Packed {
+    const ORDER: usize = 16;
+    type Elem = u8;
+
+    fn add(a: u8, b: u8) -> u8 {
+        add(a, b)
+    }
+
+    fn mul(a: u8, b: u8) -> u8 {
+        mul(a, b)
+    }
+
+    fn div(a: u8, b: u8) -> u8 {
+        div(a, b)
+    }
+
+    fn exp(elem: u8, n: usize) -> u8 {
+        exp(elem, n)
+    }
+
+    fn zero() -> u8 {
+        0
+    }
+
+    fn one() -> u8 {
+        0x11
+    }
+
+    fn nth_internal(n: usize) -> u8 {
+        (n as u8) * 0x11
+    }
+
+    fn mul_slice(c: u8, input: &[u8], out: &mut [u8]) {
+        mul_slice(c, input, out)
+    }
+
+    fn mul_slice_add(c: u8, input: &[u8], out: &mut [u8]) {
+        mul_slice_xor(c, input, out)
+    }
+}
+
+// Adapts a byte shard to a shard of the inner codec, the elements of both
+// are bytes.
+struct ByteShard<'a, T>(&'a mut T);
+
+impl<'a, T: ReconstructShard<galois_8::Field>> ReconstructShard<Packed> for ByteShard<'a, T> {
+    fn len(&self) -> Option<usize> {
+        self.0.len()
+    }
+
+    fn get(&mut self) -> Option<&mut [u8]> {
+        self.0.get()
+    }
+
+    fn get_or_initialize(&mut self, len: usize) -> Result<ShardState<'_, u8>, Error> {
+        self.0.get_or_initialize(len)
+    }
+}
+
+/// Reed-Solomon erasure code encoder/decoder over GF(2^4), on byte shards
+/// holding two symbols per byte.
+///
+/// Accepts the same shard types as `galois_8::ReedSolomon`, and at most 16
+/// shards in total.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReedSolomon(crate::ReedSolomon<Packed>);
+
+impl ReedSolomon {
+    /// Creates a new instance of Reed-Solomon erasure code encoder/decoder.
+    ///
+    /// Returns `Error::TooManyShards` if `data_shards + parity_shards > 16`.
+    pub fn new(data_shards: usize, parity_shards: usize) -> Result<ReedSolomon, Error> {
+        crate::ReedSolomon::new(data_shards, parity_shards).map(ReedSolomon)
+    }
+
+    pub fn data_shard_count(&self) -> usize {
+        self.0.data_shard_count()
+    }
+
+    pub fn parity_shard_count(&self) -> usize {
+        self.0.parity_shard_count()
+    }
+
+    pub fn total_shard_count(&self) -> usize {
+        self.0.total_shard_count()
+    }
+
+    /// Constructs the parity shards.
+    ///
+    /// The slots where the parity shards sit at will be overwritten.
+    pub fn encode<T, U>(&self, shards: T) -> Result<(), Error>
+    where
+        T: AsRef<[U]> + AsMut<[U]>,
+        U: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        self.0.encode(shards)
+    }
+
+    /// Constructs the parity shards using a read-only view into the
+    /// data shards.
+    ///
+    /// The slots where the parity shards sit at will be overwritten.
+    pub fn encode_sep<T: AsRef<[u8]>, U: AsRef<[u8]> + AsMut<[u8]>>(
+        &self,
+        data: &[T],
+        parity: &mut [U],
+    ) -> Result<(), Error> {
+        self.0.encode_sep(data, parity)
+    }
+
+    /// Checks if the parity shards are correct.
+    pub fn verify<T: AsRef<[u8]>>(&self, slices: &[T]) -> Result<bool, Error> {
+        self.0.verify(slices)
+    }
+
+    /// Reconstructs all shards.
+    ///
+    /// See `crate::ReedSolomon::reconstruct`.
+    pub fn reconstruct<T: ReconstructShard<galois_8::Field>>(
+        &self,
+        slices: &mut [T],
+    ) -> Result<(), Error> {
+        let mut shards: SmallVec<[ByteShard<T>; 16]> = slices.iter_mut().map(ByteShard).collect();
+        self.0.reconstruct(&mut shards)
+    }
+
+    /// Reconstructs only the data shards.
+    ///
+    /// See `crate::ReedSolomon::reconstruct_data`.
+    pub fn reconstruct_data<T: ReconstructShard<galois_8::Field>>(
+        &self,
+        slices: &mut [T],
+    ) -> Result<(), Error> {
+        let mut shards: SmallVec<[ByteShard<T>; 16]> = slices.iter_mut().map(ByteShard).collect();
+        self.0.reconstruct_data(&mut shards)
+    }
+}
+
+/// Add two pairs of symbols.
+pub fn add(a: u8, b: u8) -> u8 {
+    a ^ b
+}
+
+/// Multiply two pairs of symbols.
+pub fn mul(a: u8, b: u8) -> u8 {
+    let low = MUL_TABLE[(a & 0x0F) as usize][(b & 0x0F) as usize];
+    let high = MUL_TABLE[(a >> 4) as usize][(b >> 4) as usize];
+    low | (high << 4)
+}
+
+/// Divide one pair of symbols by another. Neither symbol of `b`, the divisor,
+/// may be 0.
+pub fn div(a: u8, b: u8) -> u8 {
+    if b & 0x0F == 0 || b & 0xF0 == 0 {
+        panic!("Divisor is 0")
+    }
+
+    let inv = INV_TABLE[(b & 0x0F) as usize] | (INV_TABLE[(b >> 4) as usize] << 4);
+    mul(a, inv)
+}
+
+/// Compute a^n.
+pub fn exp(a: u8, n: usize) -> u8 {
+    let mut result = 0x11;
+    let mut a = a;
+    let mut n = n;
+    while n > 0 {
+        if n & 1 == 1 {
+            result = mul(result, a);
+        }
+        a = mul(a, a);
+        n >>= 1;
+    }
+    result
+}
+
+/// Multiply a slice of packed symbols by `c`. Writes into the output slice.
+///
+/// # Panics
+/// Panics if the output slice does not have equal length to the input.
+pub fn mul_slice(c: u8, input: &[u8], out: &mut [u8]) {
+    assert_eq!(input.len(), out.len());
+
+    let low = &MUL_TABLE[(c & 0x0F) as usize];
+    let high = &MUL_TABLE[(c >> 4) as usize];
+    for (i, o) in input.iter().zip(out.iter_mut()) {
+        *o = low[(*i & 0x0F) as usize] | (high[(*i >> 4) as usize] << 4);
+    }
+}
+
+/// Multiply a slice of packed symbols by `c`, adding each result to the
+/// corresponding value in `out`.
+///
+/// # Panics
+/// Panics if the output slice does not have equal length to the input.
+pub fn mul_slice_xor(c: u8, input: &[u8], out: &mut [u8]) {
+    assert_eq!(input.len(), out.len());
+
+    let low = &MUL_TABLE[(c & 0x0F) as usize];
+    let high = &MUL_TABLE[(c >> 4) as usize];
+    for (i, o) in input.iter().zip(out.iter_mut()) {
+        *o ^= low[(*i & 0x0F) as usize] | (high[(*i >> 4) as usize] << 4);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::tests::fill_random;
+    use crate::ErrorKind;
+
+    #[test]
+    fn test_field_axioms() {
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                assert_eq!(mul(a, b), mul(b, a));
+                for c in [0, 1, 0x11, 0x5A, 0xFF, b.rotate_left(3)].iter().cloned() {
+                    assert_eq!(mul(a, mul(b, c)), mul(mul(a, b), c));
+                    assert_eq!(mul(a, add(b, c)), add(mul(a, b), mul(a, c)));
+                }
+            }
+            if a & 0x0F != 0 && a & 0xF0 != 0 {
+                assert_eq!(mul(a, div(0x11, a)), 0x11);
+            }
+        }
+    }
+
+    #[test]
+    fn test_packed_conformance() {
+        // Only 16 of the 256 bytes are elements of `Packed`.
+        crate::field_conformance::check::<Packed>();
+    }
+
+    #[test]
+    fn test_known_values() {
+        // x^4 = x + 1
+        assert_eq!(MUL_TABLE[8][2], 3);
+        assert_eq!(mul(0x82, 0x28), 0x33);
+        assert_eq!(exp(0x22, 15), 0x11);
+        assert_eq!(exp(0x22, 4), 0x33);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_b_is_0() {
+        div(0x11, 0x10);
+    }
+
+    #[test]
+    fn test_mul_slice_same_as_scalar_mul() {
+        for &len in [0, 1, 7, 1000].iter() {
+            let c = rand::random::<u8>();
+            let mut input = vec![0; len];
+            fill_random(&mut input);
+            let mut orig = vec![0; len];
+            fill_random(&mut orig);
+
+            let mut output = orig.clone();
+            mul_slice(c, &input, &mut output);
+            for i in 0..len {
+                assert_eq!(mul(c, input[i]), output[i]);
+            }
+
+            let mut output = orig.clone();
+            mul_slice_xor(c, &input, &mut output);
+            for i in 0..len {
+                assert_eq!(orig[i] ^ mul(c, input[i]), output[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_order_restriction() {
+        assert!(ReedSolomon::new(10, 6).is_ok());
+        assert_eq!(
+            ErrorKind::TooManyShards,
+            ReedSolomon::new(10, 7).unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn test_encode_reconstruct() {
+        let r = ReedSolomon::new(10, 6).unwrap();
+
+        let mut expect: Vec<Vec<u8>> = vec![vec![0; 100]; 16];
+        for s in expect[..10].iter_mut() {
+            fill_random(s);
+        }
+        r.encode(&mut expect).unwrap();
+        assert!(r.verify(&expect).unwrap());
+
+        let mut shards: Vec<_> = expect.iter().cloned().map(Some).collect();
+        for i in [0, 3, 4, 9, 11, 15].iter() {
+            shards[*i] = None;
+        }
+        r.reconstruct(&mut shards).unwrap();
+
+        let shards: Vec<_> = shards.into_iter().map(Option::unwrap).collect();
+        assert_eq!(expect, shards);
+
+        // Erasing some data shards only.
+        let mut shards: Vec<_> = expect.iter().cloned().map(Some).collect();
+        shards[2] = None;
+        shards[12] = None;
+        r.reconstruct_data(&mut shards).unwrap();
+        assert_eq!(Some(&expect[2]), shards[2].as_ref());
+        assert_eq!(None, shards[12]);
+
+        // Too many erasures.
+        let mut shards: Vec<_> = expect.iter().cloned().map(Some).collect();
+        for shard in shards[..7].iter_mut() {
+            *shard = None;
+        }
+        assert_eq!(
+            ErrorKind::TooFewShardsPresent,
+            r.reconstruct(&mut shards).unwrap_err().kind()
+        );
+
+        let mut corrupt = expect.clone();
+        corrupt[5][50] ^= 0x10;
+        assert!(!r.verify(&corrupt).unwrap());
+
+        let (data, parity) = corrupt.split_at_mut(10);
+        r.encode_sep(data, parity).unwrap();
+        assert!(r.verify(&corrupt).unwrap());
+    }
+
+    #[test]
+    fn test_codec_is_two_nibble_codecs() {
+        // Coding only the low nibbles gives the low nibbles of the full
+        // parity, so the nibbles do not mix.
+        let r = ReedSolomon::new(4, 3).unwrap();
+        let mut full: Vec<Vec<u8>> = vec![vec![0; 64]; 7];
+        for s in full[..4].iter_mut() {
+            fill_random(s);
+        }
+        let mut low: Vec<Vec<u8>> = full
+            .iter()
+            .map(|s| s.iter().map(|b| b & 0x0F).collect())
+            .collect();
+        r.encode(&mut full).unwrap();
+        r.encode(&mut low).unwrap();
+        for (f, l) in full.iter().zip(low.iter()) {
+            let f: Vec<u8> = f.iter().map(|b| b & 0x0F).collect();
+            assert_eq!(&f, l);
+        }
+    }
+}
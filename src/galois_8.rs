@@ -1,6 +1,44 @@
 //! Implementation of GF(2^8): the finite field with 2^8 elements.
 
-include!(concat!(env!("OUT_DIR"), "/table.rs"));
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// x^8 + x^4 + x^3 + x^2 + 1, without the x^8 term.
+const GENERATING_POLYNOMIAL: usize = 29;
+
+const LOG: [u8; 256] = gen_log_table(GENERATING_POLYNOMIAL);
+const EXP: [u8; 510] = gen_exp_table(&LOG);
+
+/// Logarithms of all non-zero elements to base 2, i.e. `x`.
+pub static LOG_TABLE: [u8; 256] = LOG;
+
+/// Powers of 2 for exponents `0..510`, doubled in length so a sum of two
+/// logarithms needs no reduction.
+pub static EXP_TABLE: [u8; 510] = EXP;
+
+/// Products of all pairs of elements.
+#[cfg(not(feature = "small-tables"))]
+pub static MUL_TABLE: [[u8; 256]; 256] = gen_mul_table(&LOG, &EXP);
+
+#[cfg(any(
+    feature = "simd-accel",
+    all(target_arch = "wasm32", target_feature = "simd128")
+))]
+const MUL_TABLE_HALF: ([[u8; 16]; 256], [[u8; 16]; 256]) = gen_mul_table_half(&LOG, &EXP);
+
+/// Products of every element with the elements `0..16`, i.e. the low nibble.
+#[cfg(any(
+    feature = "simd-accel",
+    all(target_arch = "wasm32", target_feature = "simd128")
+))]
+pub static MUL_TABLE_LOW: [[u8; 16]; 256] = MUL_TABLE_HALF.0;
+
+/// Products of every element with the elements `0x00, 0x10, ..., 0xF0`,
+/// i.e. the high nibble.
+#[cfg(any(
+    feature = "simd-accel",
+    all(target_arch = "wasm32", target_feature = "simd128")
+))]
+pub static MUL_TABLE_HIGH: [[u8; 16]; 256] = MUL_TABLE_HALF.1;
 
 /// The field GF(2^8).
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
@@ -65,10 +103,23 @@ pub fn sub(a: u8, b: u8) -> u8 {
 }
 
 /// Multiply two elements.
+#[cfg(not(feature = "small-tables"))]
 pub fn mul(a: u8, b: u8) -> u8 {
     MUL_TABLE[a as usize][b as usize]
 }
 
+/// Multiply two elements.
+#[cfg(feature = "small-tables")]
+pub fn mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        let log_a = LOG_TABLE[a as usize];
+        let log_b = LOG_TABLE[b as usize];
+        EXP_TABLE[log_a as usize + log_b as usize]
+    }
+}
+
 /// Divide one element by another. `b`, the divisor, may not be 0.
 pub fn div(a: u8, b: u8) -> u8 {
     if a == 0 {
@@ -86,6 +137,15 @@ pub fn div(a: u8, b: u8) -> u8 {
     }
 }
 
+/// Divide one element by another, returning `None` if `b`, the divisor, is 0.
+pub fn checked_div(a: u8, b: u8) -> Option<u8> {
+    if b == 0 {
+        None
+    } else {
+        Some(div(a, b))
+    }
+}
+
 /// Compute a^n.
 pub fn exp(a: u8, n: usize) -> u8 {
     if n == 0 {
@@ -102,160 +162,551 @@ pub fn exp(a: u8, n: usize) -> u8 {
     }
 }
 
-const PURE_RUST_UNROLL: isize = 4;
+/// An element of GF(2^8), with the arithmetic of `Field`.
+///
+/// ```
+/// use reed_solomon_erasure::galois_8::Gf256;
+///
+/// let a = Gf256(3);
+/// let b = Gf256(7);
+/// assert_eq!((a * b) / b, a);
+/// assert_eq!(a.pow(2), a * a);
+/// assert_eq!(Gf256::antilog(a.log().unwrap()), a);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Gf256(pub u8);
+
+impl Gf256 {
+    /// The additive identity.
+    pub const ZERO: Gf256 = Gf256(0);
+
+    /// The multiplicative identity.
+    pub const ONE: Gf256 = Gf256(1);
+
+    /// The generator of the multiplicative group used for `log` and `antilog`.
+    pub const GENERATOR: Gf256 = Gf256(2);
+
+    /// The multiplicative inverse.
+    ///
+    /// # Panics
+    /// Panics if the element is zero.
+    pub fn inv(self) -> Gf256 {
+        Gf256(div(1, self.0))
+    }
+
+    /// Raise the element to the power `n`.
+    pub fn pow(self, n: usize) -> Gf256 {
+        Gf256(exp(self.0, n))
+    }
 
-macro_rules! return_if_empty {
-    (
-        $len:expr
-    ) => {
-        if $len == 0 {
-            return;
+    /// The logarithm to the base `GENERATOR`, or `None` for zero.
+    pub fn log(self) -> Option<usize> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(LOG_TABLE[self.0 as usize] as usize)
         }
-    };
+    }
+
+    /// `GENERATOR` raised to the power `n`.
+    pub fn antilog(n: usize) -> Gf256 {
+        Gf256(EXP_TABLE[n % 255])
+    }
+
+    /// Iterate over all elements, in increasing order of their byte value.
+    pub fn all() -> impl Iterator<Item = Gf256> {
+        (0..=u8::MAX).map(Gf256)
+    }
 }
 
-#[cfg(not(all(
-    feature = "simd-accel",
-    any(target_arch = "x86_64", target_arch = "aarch64"),
-    not(target_env = "msvc"),
-    not(any(target_os = "android", target_os = "ios"))
+impl From<u8> for Gf256 {
+    fn from(a: u8) -> Gf256 {
+        Gf256(a)
+    }
+}
+
+impl From<Gf256> for u8 {
+    fn from(a: Gf256) -> u8 {
+        a.0
+    }
+}
+
+impl Add for Gf256 {
+    type Output = Gf256;
+
+    fn add(self, rhs: Gf256) -> Gf256 {
+        Gf256(add(self.0, rhs.0))
+    }
+}
+
+impl Sub for Gf256 {
+    type Output = Gf256;
+
+    fn sub(self, rhs: Gf256) -> Gf256 {
+        Gf256(add(self.0, rhs.0))
+    }
+}
+
+impl Neg for Gf256 {
+    type Output = Gf256;
+
+    fn neg(self) -> Gf256 {
+        self
+    }
+}
+
+impl Mul for Gf256 {
+    type Output = Gf256;
+
+    fn mul(self, rhs: Gf256) -> Gf256 {
+        Gf256(mul(self.0, rhs.0))
+    }
+}
+
+impl Div for Gf256 {
+    type Output = Gf256;
+
+    fn div(self, rhs: Gf256) -> Gf256 {
+        Gf256(div(self.0, rhs.0))
+    }
+}
+
+impl AddAssign for Gf256 {
+    fn add_assign(&mut self, rhs: Gf256) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Gf256 {
+    fn sub_assign(&mut self, rhs: Gf256) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Gf256 {
+    fn mul_assign(&mut self, rhs: Gf256) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Gf256 {
+    fn div_assign(&mut self, rhs: Gf256) {
+        *self = *self / rhs;
+    }
+}
+
+/// The field GF(2^8) over a caller-chosen polynomial.
+///
+/// `POLY` is the generating polynomial including the `x^8` term,
+/// e.g. `0x11B` for the AES field or `0x11D` for the one used by `Field`.
+/// The log and exp tables are computed at compile time.
+///
+/// The polynomial has to be irreducible, which is checked when the tables are
+/// built, so a reducible polynomial is a compile error:
+///
+/// ```compile_fail
+/// # use reed_solomon_erasure::Field as _;
+/// use reed_solomon_erasure::galois_8::CustomField;
+/// // x^8 + 1 = (x + 1)^8
+/// CustomField::<0x101>::mul(2, 3);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct CustomField<const POLY: u16>;
+
+/// Type alias of ReedSolomon over GF(2^8) with a caller-chosen polynomial.
+pub type CustomReedSolomon<const POLY: u16> = crate::ReedSolomon<CustomField<POLY>>;
+
+// Log and exp tables of a GF(2^8) over some generator of the multiplicative
+// group. `exp` is doubled in length so a sum of two logs needs no reduction.
+struct Tables {
+    log: [u8; 256],
+    exp: [u8; 510],
+}
+
+impl<const POLY: u16> CustomField<POLY> {
+    const TABLES: Tables = gen_tables(POLY);
+
+    fn tables() -> &'static Tables {
+        &Self::TABLES
+    }
+}
+
+impl<const POLY: u16> crate::Field for CustomField<POLY> {
+    const ORDER: usize = 256;
+    type Elem = u8;
+
+    fn add(a: u8, b: u8) -> u8 {
+        a ^ b
+    }
+
+    fn mul(a: u8, b: u8) -> u8 {
+        if a == 0 || b == 0 {
+            0
+        } else {
+            let t = Self::tables();
+            t.exp[t.log[a as usize] as usize + t.log[b as usize] as usize]
+        }
+    }
+
+    fn div(a: u8, b: u8) -> u8 {
+        if a == 0 {
+            0
+        } else if b == 0 {
+            panic!("Divisor is 0")
+        } else {
+            let t = Self::tables();
+            t.exp[t.log[a as usize] as usize + 255 - t.log[b as usize] as usize]
+        }
+    }
+
+    fn exp(a: u8, n: usize) -> u8 {
+        if n == 0 {
+            1
+        } else if a == 0 {
+            0
+        } else {
+            let t = Self::tables();
+            t.exp[t.log[a as usize] as usize * (n % 255) % 255]
+        }
+    }
+
+    fn zero() -> u8 {
+        0
+    }
+
+    fn one() -> u8 {
+        1
+    }
+
+    fn nth_internal(n: usize) -> u8 {
+        n as u8
+    }
+
+    fn mul_slice(c: u8, input: &[u8], out: &mut [u8]) {
+        assert_eq!(input.len(), out.len());
+
+        let row = Self::mul_row(c);
+        for (i, o) in input.iter().zip(out.iter_mut()) {
+            *o = row[*i as usize];
+        }
+    }
+
+    fn mul_slice_add(c: u8, input: &[u8], out: &mut [u8]) {
+        assert_eq!(input.len(), out.len());
+
+        let row = Self::mul_row(c);
+        for (i, o) in input.iter().zip(out.iter_mut()) {
+            *o ^= row[*i as usize];
+        }
+    }
+}
+
+impl<const POLY: u16> CustomField<POLY> {
+    // Products of `c` with every element, so the slice kernels do a single
+    // lookup per byte.
+    fn mul_row(c: u8) -> [u8; 256] {
+        let mut row = [0u8; 256];
+        if c != 0 {
+            let t = Self::tables();
+            let log_c = t.log[c as usize] as usize;
+            for (b, r) in row.iter_mut().enumerate().skip(1) {
+                *r = t.exp[log_c + t.log[b] as usize];
+            }
+        }
+        row
+    }
+}
+
+// Carry-less multiplication of two elements, reduced by `poly`.
+const fn mul_poly(mut a: u16, mut b: u16, poly: u16) -> u16 {
+    let mut result = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            result ^= a;
+        }
+        b >>= 1;
+        a <<= 1;
+        if a & 0x100 != 0 {
+            a ^= poly;
+        }
+    }
+    result
+}
+
+const fn exp_poly(a: u16, mut n: usize, poly: u16) -> u16 {
+    let mut result = 1;
+    while n > 0 {
+        result = mul_poly(result, a, poly);
+        n -= 1;
+    }
+    result
+}
+
+// Degree of a non-zero polynomial.
+const fn degree(p: u16) -> u32 {
+    15 - p.leading_zeros()
+}
+
+/// Check whether `poly` is an irreducible polynomial of degree 8.
+///
+/// `poly` includes the `x^8` term, e.g. `0x11D`.
+pub const fn is_irreducible(poly: u16) -> bool {
+    if poly >> 8 != 1 {
+        return false;
+    }
+
+    // Trial division by every polynomial of degree 1 to 4.
+    let mut d: u16 = 2;
+    while d < 32 {
+        let mut rem = poly;
+        while rem != 0 && degree(rem) >= degree(d) {
+            rem ^= d << (degree(rem) - degree(d));
+        }
+        if rem == 0 {
+            return false;
+        }
+        d += 1;
+    }
+
+    true
+}
+
+const fn gen_tables(poly: u16) -> Tables {
+    assert!(
+        is_irreducible(poly),
+        "generating polynomial must be irreducible and of degree 8"
+    );
+
+    // Find a generator of the multiplicative group, of order 255 = 3 * 5 * 17.
+    // This is `x` for primitive polynomials, but not for e.g. 0x11B.
+    let mut g = 2;
+    while exp_poly(g, 255 / 3, poly) == 1
+        || exp_poly(g, 255 / 5, poly) == 1
+        || exp_poly(g, 255 / 17, poly) == 1
+    {
+        g += 1;
+    }
+
+    let mut log = [0u8; 256];
+
+    let mut x = 1;
+    let mut i = 0;
+    while i < 255 {
+        log[x as usize] = i as u8;
+
+        x = mul_poly(x, g, poly);
+        i += 1;
+    }
+
+    let exp = gen_exp_table(&log);
+
+    Tables { log, exp }
+}
+
+/// Generate the log table of GF(2^8) to base 2, i.e. `x`.
+///
+/// `polynomial` is the generating polynomial without the `x^8` term,
+/// e.g. `29` for the one used by `Field`. It has to be primitive, so that `x`
+/// generates the multiplicative group. Log of 0 is left as 0.
+///
+/// Like the other table generators this is a `const fn`, so tables for other
+/// polynomials can be built at compile time:
+///
+/// ```
+/// use reed_solomon_erasure::galois_8::{gen_exp_table, gen_log_table};
+///
+/// // x^8 + x^6 + x^5 + x^3 + 1
+/// const LOG: [u8; 256] = gen_log_table(0x69);
+/// const EXP: [u8; 510] = gen_exp_table(&LOG);
+///
+/// assert_eq!(EXP[LOG[3] as usize], 3);
+/// ```
+pub const fn gen_log_table(polynomial: usize) -> [u8; 256] {
+    let poly = (polynomial | 0x100) as u16;
+    let mut result = [0u8; 256];
+
+    let mut b = 1;
+    let mut log = 0;
+    while log < 255 {
+        assert!(log == 0 || b != 1, "generating polynomial must be primitive");
+        result[b as usize] = log as u8;
+
+        b = mul_poly(b, 2, poly);
+        log += 1;
+    }
+
+    result
+}
+
+/// Generate the exp table matching `log_table`, of length `2 * 255` so a
+/// sum of two logs can be looked up without reduction.
+pub const fn gen_exp_table(log_table: &[u8; 256]) -> [u8; 510] {
+    let mut result = [0u8; 510];
+
+    let mut i = 1;
+    while i < 256 {
+        let log = log_table[i] as usize;
+        result[log] = i as u8;
+        result[log + 255] = i as u8;
+        i += 1;
+    }
+
+    result
+}
+
+const fn multiply(log_table: &[u8; 256], exp_table: &[u8; 510], a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        let log_a = log_table[a as usize];
+        let log_b = log_table[b as usize];
+        exp_table[log_a as usize + log_b as usize]
+    }
+}
+
+/// Generate the table of products of all pairs of elements.
+pub const fn gen_mul_table(log_table: &[u8; 256], exp_table: &[u8; 510]) -> [[u8; 256]; 256] {
+    let mut result = [[0u8; 256]; 256];
+
+    let mut a = 0;
+    while a < 256 {
+        let mut b = 0;
+        while b < 256 {
+            result[a][b] = multiply(log_table, exp_table, a as u8, b as u8);
+            b += 1;
+        }
+        a += 1;
+    }
+
+    result
+}
+
+/// Generate the tables of products of every element with the low nibbles
+/// `0..16` and the high nibbles `0x00, 0x10, ..., 0xF0`, as used by the SIMD
+/// kernels.
+pub const fn gen_mul_table_half(
+    log_table: &[u8; 256],
+    exp_table: &[u8; 510],
+) -> ([[u8; 16]; 256], [[u8; 16]; 256]) {
+    let mut low = [[0u8; 16]; 256];
+    let mut high = [[0u8; 16]; 256];
+
+    let mut a = 0;
+    while a < 256 {
+        let mut b = 0;
+        while b < 16 {
+            low[a][b] = multiply(log_table, exp_table, a as u8, b as u8);
+            high[a][b] = multiply(log_table, exp_table, a as u8, (b << 4) as u8);
+            b += 1;
+        }
+        a += 1;
+    }
+
+    (low, high)
+}
+
+#[cfg(not(any(
+    all(
+        feature = "simd-accel",
+        any(target_arch = "x86_64", target_arch = "aarch64"),
+        not(target_env = "msvc"),
+        not(any(target_os = "android", target_os = "ios"))
+    ),
+    all(target_arch = "wasm32", target_feature = "simd128")
 )))]
 pub fn mul_slice(c: u8, input: &[u8], out: &mut [u8]) {
     mul_slice_pure_rust(c, input, out);
 }
 
-#[cfg(not(all(
-    feature = "simd-accel",
-    any(target_arch = "x86_64", target_arch = "aarch64"),
-    not(target_env = "msvc"),
-    not(any(target_os = "android", target_os = "ios"))
+#[cfg(not(any(
+    all(
+        feature = "simd-accel",
+        any(target_arch = "x86_64", target_arch = "aarch64"),
+        not(target_env = "msvc"),
+        not(any(target_os = "android", target_os = "ios"))
+    ),
+    all(target_arch = "wasm32", target_feature = "simd128")
 )))]
 pub fn mul_slice_xor(c: u8, input: &[u8], out: &mut [u8]) {
     mul_slice_xor_pure_rust(c, input, out);
 }
 
+// The pure Rust kernels avoid a table lookup per byte, which the optimiser
+// cannot vectorise. Instead `c * x` is computed as the sum of `c * 2^k` over
+// the bits `k` set in `x`: only shifts, masks and XORs, which it turns into
+// SIMD code over the fixed-size chunks. No multiplication table is needed,
+// so `small-tables` uses them too.
+const PURE_RUST_CHUNK: usize = 32;
+
+// `c * 2^k` for `k` in `0..8`.
+fn bit_products(c: u8) -> [u8; 8] {
+    let mut products = [0; 8];
+    let mut x = c;
+    for p in products.iter_mut() {
+        *p = x;
+        x = mul(x, 2);
+    }
+    products
+}
+
+#[inline(always)]
+fn mul_bits(products: &[u8; 8], x: u8) -> u8 {
+    let mut result = 0;
+    for (k, p) in products.iter().enumerate() {
+        // All ones if bit `k` of `x` is set.
+        let mask = 0u8.wrapping_sub((x >> k) & 1);
+        result ^= mask & p;
+    }
+    result
+}
+
 fn mul_slice_pure_rust(c: u8, input: &[u8], out: &mut [u8]) {
-    let mt = &MUL_TABLE[c as usize];
-    let mt_ptr: *const u8 = &mt[0];
+    let products = bit_products(c);
 
     assert_eq!(input.len(), out.len());
 
-    let len: isize = input.len() as isize;
-    return_if_empty!(len);
-
-    let mut input_ptr: *const u8 = &input[0];
-    let mut out_ptr: *mut u8 = &mut out[0];
-
-    let mut n: isize = 0;
-    unsafe {
-        assert_eq!(4, PURE_RUST_UNROLL);
-        if len > PURE_RUST_UNROLL {
-            let len_minus_unroll = len - PURE_RUST_UNROLL;
-            while n < len_minus_unroll {
-                *out_ptr = *mt_ptr.offset(*input_ptr as isize);
-                *out_ptr.offset(1) = *mt_ptr.offset(*input_ptr.offset(1) as isize);
-                *out_ptr.offset(2) = *mt_ptr.offset(*input_ptr.offset(2) as isize);
-                *out_ptr.offset(3) = *mt_ptr.offset(*input_ptr.offset(3) as isize);
-
-                input_ptr = input_ptr.offset(PURE_RUST_UNROLL);
-                out_ptr = out_ptr.offset(PURE_RUST_UNROLL);
-                n += PURE_RUST_UNROLL;
-            }
-        }
-        while n < len {
-            *out_ptr = *mt_ptr.offset(*input_ptr as isize);
+    let mut input_chunks = input.chunks_exact(PURE_RUST_CHUNK);
+    let mut out_chunks = out.chunks_exact_mut(PURE_RUST_CHUNK);
 
-            input_ptr = input_ptr.offset(1);
-            out_ptr = out_ptr.offset(1);
-            n += 1;
+    for (i, o) in (&mut input_chunks).zip(&mut out_chunks) {
+        for (i, o) in i.iter().zip(o.iter_mut()) {
+            *o = mul_bits(&products, *i);
         }
     }
-    /* for n in 0..input.len() {
-     *   out[n] = mt[input[n] as usize]
-     * }
-     */
+
+    let input_rem = input_chunks.remainder();
+    for (i, o) in input_rem.iter().zip(out_chunks.into_remainder()) {
+        *o = mul_bits(&products, *i);
+    }
 }
 
 fn mul_slice_xor_pure_rust(c: u8, input: &[u8], out: &mut [u8]) {
-    let mt = &MUL_TABLE[c as usize];
-    let mt_ptr: *const u8 = &mt[0];
+    let products = bit_products(c);
 
     assert_eq!(input.len(), out.len());
 
-    let len: isize = input.len() as isize;
-    return_if_empty!(len);
-
-    let mut input_ptr: *const u8 = &input[0];
-    let mut out_ptr: *mut u8 = &mut out[0];
-
-    let mut n: isize = 0;
-    unsafe {
-        assert_eq!(4, PURE_RUST_UNROLL);
-        if len > PURE_RUST_UNROLL {
-            let len_minus_unroll = len - PURE_RUST_UNROLL;
-            while n < len_minus_unroll {
-                *out_ptr ^= *mt_ptr.offset(*input_ptr as isize);
-                *out_ptr.offset(1) ^= *mt_ptr.offset(*input_ptr.offset(1) as isize);
-                *out_ptr.offset(2) ^= *mt_ptr.offset(*input_ptr.offset(2) as isize);
-                *out_ptr.offset(3) ^= *mt_ptr.offset(*input_ptr.offset(3) as isize);
-
-                input_ptr = input_ptr.offset(PURE_RUST_UNROLL);
-                out_ptr = out_ptr.offset(PURE_RUST_UNROLL);
-                n += PURE_RUST_UNROLL;
-            }
-        }
-        while n < len {
-            *out_ptr ^= *mt_ptr.offset(*input_ptr as isize);
+    let mut input_chunks = input.chunks_exact(PURE_RUST_CHUNK);
+    let mut out_chunks = out.chunks_exact_mut(PURE_RUST_CHUNK);
 
-            input_ptr = input_ptr.offset(1);
-            out_ptr = out_ptr.offset(1);
-            n += 1;
+    for (i, o) in (&mut input_chunks).zip(&mut out_chunks) {
+        for (i, o) in i.iter().zip(o.iter_mut()) {
+            *o ^= mul_bits(&products, *i);
         }
     }
-    /* for n in 0..input.len() {
-     *   out[n] ^= mt[input[n] as usize];
-     * }
-     */
+
+    let input_rem = input_chunks.remainder();
+    for (i, o) in input_rem.iter().zip(out_chunks.into_remainder()) {
+        *o ^= mul_bits(&products, *i);
+    }
 }
 
 #[cfg(test)]
 fn slice_xor(input: &[u8], out: &mut [u8]) {
     assert_eq!(input.len(), out.len());
 
-    let len: isize = input.len() as isize;
-    return_if_empty!(len);
-
-    let mut input_ptr: *const u8 = &input[0];
-    let mut out_ptr: *mut u8 = &mut out[0];
-
-    let mut n: isize = 0;
-    unsafe {
-        assert_eq!(4, PURE_RUST_UNROLL);
-        if len > PURE_RUST_UNROLL {
-            let len_minus_unroll = len - PURE_RUST_UNROLL;
-            while n < len_minus_unroll {
-                *out_ptr ^= *input_ptr;
-                *out_ptr.offset(1) ^= *input_ptr.offset(1);
-                *out_ptr.offset(2) ^= *input_ptr.offset(2);
-                *out_ptr.offset(3) ^= *input_ptr.offset(3);
-
-                input_ptr = input_ptr.offset(PURE_RUST_UNROLL);
-                out_ptr = out_ptr.offset(PURE_RUST_UNROLL);
-                n += PURE_RUST_UNROLL;
-            }
-        }
-        while n < len {
-            *out_ptr ^= *input_ptr;
-
-            input_ptr = input_ptr.offset(1);
-            out_ptr = out_ptr.offset(1);
-            n += 1;
-        }
+    for (i, o) in input.iter().zip(out.iter_mut()) {
+        *o ^= *i;
     }
-    /* for n in 0..input.len() {
-     *   out[n] ^= input[n]
-     * }
-     */
 }
 
 #[cfg(all(
@@ -294,8 +745,9 @@ pub fn mul_slice(c: u8, input: &[u8], out: &mut [u8]) {
 
     assert_eq!(input.len(), out.len());
 
-    let input_ptr: *const u8 = &input[0];
-    let out_ptr: *mut u8 = &mut out[0];
+    // Not `&input[0]`, so that empty slices are fine.
+    let input_ptr: *const u8 = input.as_ptr();
+    let out_ptr: *mut u8 = out.as_mut_ptr();
     let size: libc::size_t = input.len();
 
     let bytes_done: usize =
@@ -316,8 +768,8 @@ pub fn mul_slice_xor(c: u8, input: &[u8], out: &mut [u8]) {
 
     assert_eq!(input.len(), out.len());
 
-    let input_ptr: *const u8 = &input[0];
-    let out_ptr: *mut u8 = &mut out[0];
+    let input_ptr: *const u8 = input.as_ptr();
+    let out_ptr: *mut u8 = out.as_mut_ptr();
     let size: libc::size_t = input.len();
 
     let bytes_done: usize =
@@ -326,6 +778,84 @@ pub fn mul_slice_xor(c: u8, input: &[u8], out: &mut [u8]) {
     mul_slice_xor_pure_rust(c, &input[bytes_done..], &mut out[bytes_done..]);
 }
 
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod wasm_simd {
+    use core::arch::wasm32::*;
+
+    // Split-nibble multiplication: the 16 byte low and high tables of the
+    // coefficient are used as shuffle sources, indexed by the low and high
+    // nibble of every input byte, and the two halves are XORed together.
+    //
+    // Returns the number of bytes processed, which is always a multiple of 16.
+    // The remainder is left to the pure Rust version.
+    #[inline(always)]
+    fn gal_mul_impl<const XOR: bool>(
+        low: &[u8; 16],
+        high: &[u8; 16],
+        input: &[u8],
+        out: &mut [u8],
+    ) -> usize {
+        let low = unsafe { v128_load(low.as_ptr() as *const v128) };
+        let high = unsafe { v128_load(high.as_ptr() as *const v128) };
+        let mask = u8x16_splat(0x0F);
+
+        let mut done = 0;
+        for (i, o) in input.chunks_exact(16).zip(out.chunks_exact_mut(16)) {
+            let i_ptr = i.as_ptr() as *const v128;
+            let o_ptr = o.as_mut_ptr() as *mut v128;
+
+            let in_v = unsafe { v128_load(i_ptr) };
+            let low_idx = v128_and(in_v, mask);
+            let high_idx = u8x16_shr(in_v, 4);
+            let mut res = v128_xor(u8x16_swizzle(low, low_idx), u8x16_swizzle(high, high_idx));
+            if XOR {
+                res = v128_xor(res, unsafe { v128_load(o_ptr) });
+            }
+            unsafe { v128_store(o_ptr, res) };
+
+            done += 16;
+        }
+
+        done
+    }
+
+    pub fn gal_mul(low: &[u8; 16], high: &[u8; 16], input: &[u8], out: &mut [u8]) -> usize {
+        gal_mul_impl::<false>(low, high, input, out)
+    }
+
+    pub fn gal_mul_xor(low: &[u8; 16], high: &[u8; 16], input: &[u8], out: &mut [u8]) -> usize {
+        gal_mul_impl::<true>(low, high, input, out)
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+pub fn mul_slice(c: u8, input: &[u8], out: &mut [u8]) {
+    assert_eq!(input.len(), out.len());
+
+    let bytes_done = wasm_simd::gal_mul(
+        &MUL_TABLE_LOW[c as usize],
+        &MUL_TABLE_HIGH[c as usize],
+        input,
+        out,
+    );
+
+    mul_slice_pure_rust(c, &input[bytes_done..], &mut out[bytes_done..]);
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+pub fn mul_slice_xor(c: u8, input: &[u8], out: &mut [u8]) {
+    assert_eq!(input.len(), out.len());
+
+    let bytes_done = wasm_simd::gal_mul_xor(
+        &MUL_TABLE_LOW[c as usize],
+        &MUL_TABLE_HIGH[c as usize],
+        input,
+        out,
+    );
+
+    mul_slice_xor_pure_rust(c, &input[bytes_done..], &mut out[bytes_done..]);
+}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
@@ -578,6 +1108,155 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pure_rust_same_as_scalar_mul() {
+        // Every `c`, over lengths covering whole chunks and every remainder.
+        for len in (0..70).chain(256..260) {
+            for c in 0..=255 {
+                let mut input = vec![0; len];
+                fill_random(&mut input);
+                let mut output = vec![0; len];
+                fill_random(&mut output);
+                let orig = output.clone();
+
+                mul_slice_pure_rust(c, &input, &mut output);
+                for i in 0..len {
+                    assert_eq!(mul(c, input[i]), output[i]);
+                }
+
+                output.copy_from_slice(&orig);
+                mul_slice_xor_pure_rust(c, &input, &mut output);
+                for i in 0..len {
+                    assert_eq!(orig[i] ^ mul(c, input[i]), output[i]);
+                }
+            }
+        }
+    }
+
+    // Run under wasmtime by CI, see `.travis.yml`.
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    #[test]
+    fn test_wasm_simd_same_as_pure_rust() {
+        // Every `c`, over lengths covering whole vectors and every remainder.
+        for len in (0..70).chain(256..260) {
+            for c in 0..=255 {
+                let mut input = vec![0; len];
+                fill_random(&mut input);
+                let mut output = vec![0; len];
+                fill_random(&mut output);
+                let orig = output.clone();
+
+                let mut expect = orig.clone();
+                mul_slice_pure_rust(c, &input, &mut expect);
+                mul_slice(c, &input, &mut output);
+                assert_eq!(expect, output);
+
+                let mut expect = orig.clone();
+                mul_slice_xor_pure_rust(c, &input, &mut expect);
+                output.copy_from_slice(&orig);
+                mul_slice_xor(c, &input, &mut output);
+                assert_eq!(expect, output);
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_field_same_as_default() {
+        type F = CustomField<0x11D>;
+        for a in 0..256 {
+            let a = a as u8;
+            for b in 0..256 {
+                let b = b as u8;
+                assert_eq!(mul(a, b), <F as crate::Field>::mul(a, b));
+                if b != 0 {
+                    assert_eq!(div(a, b), <F as crate::Field>::div(a, b));
+                }
+            }
+            for n in 0..600 {
+                assert_eq!(exp(a, n), <F as crate::Field>::exp(a, n));
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_field_aes() {
+        use crate::Field as _;
+        type F = CustomField<0x11B>;
+
+        // Examples from FIPS-197
+        assert_eq!(F::mul(0x57, 0x83), 0xC1);
+        assert_eq!(F::mul(0x57, 0x13), 0xFE);
+        assert_eq!(F::div(1, 0x53), 0xCA);
+
+        let mut input = vec![0; 1000];
+        fill_random(&mut input);
+        let mut output = vec![0; 1000];
+        fill_random(&mut output);
+        let orig = output.clone();
+        F::mul_slice_add(0x57, &input, &mut output);
+        for i in 0..input.len() {
+            assert_eq!(orig[i] ^ F::mul(0x57, input[i]), output[i]);
+        }
+
+        let r: CustomReedSolomon<0x11B> = crate::ReedSolomon::new(4, 2).unwrap();
+        let mut shards = vec![vec![0u8; 10]; 6];
+        for s in shards[..4].iter_mut() {
+            fill_random(s);
+        }
+        r.encode(&mut shards).unwrap();
+        assert!(r.verify(&shards).unwrap());
+    }
+
+    #[test]
+    fn test_is_irreducible() {
+        assert!(is_irreducible(0x11D));
+        assert!(is_irreducible(0x11B));
+        assert!(!is_irreducible(0x101));
+        assert!(!is_irreducible(0x100));
+        assert!(!is_irreducible(0x11C));
+        assert!(!is_irreducible(0x1D));
+        assert!(!is_irreducible(0x21D));
+
+        // There are 30 irreducible polynomials of degree 8 over GF(2).
+        assert_eq!((0x100..0x200).filter(|&p| is_irreducible(p)).count(), 30);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_log_table_not_primitive() {
+        // 0x11B is irreducible, but `x` has order 51
+        gen_log_table(0x1B);
+    }
+
+    #[test]
+    fn test_gf256_same_as_functions() {
+        for a in Gf256::all() {
+            for b in Gf256::all() {
+                assert_eq!(add(a.0, b.0), (a + b).0);
+                assert_eq!(sub(a.0, b.0), (a - b).0);
+                assert_eq!(mul(a.0, b.0), (a * b).0);
+                if b != Gf256::ZERO {
+                    assert_eq!(div(a.0, b.0), (a / b).0);
+                }
+            }
+            if a != Gf256::ZERO {
+                assert_eq!(Gf256::ONE, a * a.inv());
+                assert_eq!(a, Gf256::antilog(a.log().unwrap()));
+            }
+            assert_eq!(a, -a);
+            assert_eq!(exp(a.0, 20), a.pow(20).0);
+        }
+        assert_eq!(None, Gf256::ZERO.log());
+        assert_eq!(256, Gf256::all().count());
+    }
+
+    #[test]
+    fn test_checked_div() {
+        assert_eq!(Some(div(7, 3)), checked_div(7, 3));
+        assert_eq!(Some(0), checked_div(0, 3));
+        assert_eq!(None, checked_div(7, 0));
+    }
+
     #[test]
     fn test_div_a_is_0() {
         assert_eq!(0, div(0, 100));
@@ -602,7 +1281,7 @@ mod tests {
                 let mut output_copy = output.clone();
 
                 mul_slice(c, &input, &mut output);
-                mul_slice(c, &input, &mut output_copy);
+                mul_slice_pure_rust(c, &input, &mut output_copy);
 
                 assert_eq!(output, output_copy);
             }
@@ -612,7 +1291,7 @@ mod tests {
                 let mut output_copy = output.clone();
 
                 mul_slice_xor(c, &input, &mut output);
-                mul_slice_xor(c, &input, &mut output_copy);
+                mul_slice_xor_pure_rust(c, &input, &mut output_copy);
 
                 assert_eq!(output, output_copy);
             }
@@ -22,6 +22,8 @@ extern crate smallvec;
 #[cfg(feature = "simd-accel")]
 extern crate libc;
 
+extern crate alloc;
+
 use ::core::iter;
 use ::core::iter::FromIterator;
 
@@ -29,21 +31,33 @@ use ::core::iter::FromIterator;
 mod macros;
 
 mod core;
+mod decode_cache;
 mod errors;
-mod matrix;
+pub mod matrix;
+pub mod poly;
+mod shard_set;
+#[cfg(feature = "std")]
+pub mod shared;
 
 #[cfg(test)]
 mod tests;
 
+pub mod field_conformance;
 pub mod galois_16;
+pub mod galois_16_binary;
+pub mod galois_32;
+pub mod galois_4;
 pub mod galois_8;
 
 pub use crate::errors::Error;
+pub use crate::errors::ErrorKind;
 pub use crate::errors::SBSError;
 
 pub use crate::core::ReedSolomon;
 pub use crate::core::ShardByShard;
 
+pub use crate::shard_set::ShardSet;
+
 // TODO: Can be simplified once https://github.com/rust-lang/rfcs/issues/2505 is resolved
 #[cfg(not(feature = "std"))]
 use libm::log2f as log2;
@@ -53,6 +67,8 @@ fn log2(n: f32) -> f32 {
 }
 
 /// A finite field to perform encoding over.
+///
+/// Implementations can be tested with `field_conformance::check`.
 pub trait Field: Sized {
     /// The order of the field. This is a limit on the number of shards
     /// in an encoding.
@@ -70,6 +86,17 @@ pub trait Field: Sized {
     /// Divide a by b. Panics is b is zero.
     fn div(a: Self::Elem, b: Self::Elem) -> Self::Elem;
 
+    /// Divide a by b, as `div`.
+    ///
+    /// Returns `None` if b is zero.
+    fn checked_div(a: Self::Elem, b: Self::Elem) -> Option<Self::Elem> {
+        if b == Self::zero() {
+            None
+        } else {
+            Some(Self::div(a, b))
+        }
+    }
+
     /// Raise `a` to the n'th power.
     fn exp(a: Self::Elem, n: usize) -> Self::Elem;
 
@@ -83,6 +110,8 @@ pub trait Field: Sized {
 
     /// Yield the nth element of the field. Panics if n >= ORDER.
     /// Assignment is arbitrary but must be unique to `n`.
+    ///
+    /// The codec itself goes through `try_nth`, which returns an error instead.
     fn nth(n: usize) -> Self::Elem {
         if n >= Self::ORDER {
             let pow = log2(Self::ORDER as f32) as usize;
@@ -92,6 +121,20 @@ pub trait Field: Sized {
         Self::nth_internal(n)
     }
 
+    /// Yield the nth element of the field, as `nth`.
+    ///
+    /// Returns `Error::InvalidFieldElement` if n >= ORDER.
+    fn try_nth(n: usize) -> Result<Self::Elem, Error> {
+        if n >= Self::ORDER {
+            return Err(Error::InvalidFieldElement {
+                index: n,
+                order: Self::ORDER,
+            });
+        }
+
+        Ok(Self::nth_internal(n))
+    }
+
     /// Multiply a slice of elements by another. Writes into the output slice.
     ///
     /// # Panics
@@ -118,23 +161,49 @@ pub trait Field: Sized {
     }
 }
 
+/// A shard handed to reconstruction by `ReconstructShard::get_or_initialize`.
+#[derive(PartialEq, Debug)]
+pub enum ShardState<'a, E> {
+    /// The shard is present, and is only read from.
+    Present(&'a mut [E]),
+    /// The shard is missing, and this buffer of the requested length is
+    /// overwritten with the reconstructed shard.
+    Missing(&'a mut [E]),
+    /// The shard is missing, and there is no buffer to reconstruct it into.
+    /// A parity shard is left as it is, a data shard is an error in
+    /// `reconstruct` and `reconstruct_data`.
+    Unavailable,
+}
+
 /// Something which might hold a shard.
 ///
 /// This trait is used in reconstruction, where some of the shards
 /// may be unknown.
+///
+/// It is implemented for
+///
+/// - `Option<T>`, where `None` is a missing shard, which is allocated with
+///   `FromIterator` when reconstructed. This covers `Vec<E>`, `Box<[E]>`,
+///   `BytesMut` and custom buffer types,
+/// - `(T, bool)`, where the flag tells whether the shard is present and a
+///   missing shard is reconstructed into the existing buffer. This is how to
+///   pass pre-allocated buffers, e.g. `(&mut [E], bool)` or `([E; N], bool)`.
+// `len` returns `None` for a missing shard rather than a length of zero, so
+// an `is_empty` counterpart would be misleading.
+#[allow(clippy::len_without_is_empty)]
 pub trait ReconstructShard<F: Field> {
-    /// The size of the shard data; `None` if empty.
+    /// The size of the shard data; `None` if missing.
     fn len(&self) -> Option<usize>;
 
-    /// Get a mutable reference to the shard data, returning `None` if uninitialized.
+    /// Get a mutable reference to the shard data, returning `None` if missing.
     fn get(&mut self) -> Option<&mut [F::Elem]>;
 
-    /// Get a mutable reference to the shard data, initializing it to the
-    /// given length if it was `None`. Returns an error if initialization fails.
-    fn get_or_initialize(
-        &mut self,
-        len: usize,
-    ) -> Result<&mut [F::Elem], Result<&mut [F::Elem], Error>>;
+    /// Get the shard data if present, or else a buffer of the given length to
+    /// reconstruct the shard into.
+    ///
+    /// Returns an error if no such buffer can be provided, e.g. when a
+    /// pre-allocated buffer is of the wrong length.
+    fn get_or_initialize(&mut self, len: usize) -> Result<ShardState<'_, F::Elem>, Error>;
 }
 
 impl<F: Field, T: AsRef<[F::Elem]> + AsMut<[F::Elem]> + FromIterator<F::Elem>> ReconstructShard<F>
@@ -148,20 +217,14 @@ impl<F: Field, T: AsRef<[F::Elem]> + AsMut<[F::Elem]> + FromIterator<F::Elem>> R
         self.as_mut().map(|x| x.as_mut())
     }
 
-    fn get_or_initialize(
-        &mut self,
-        len: usize,
-    ) -> Result<&mut [F::Elem], Result<&mut [F::Elem], Error>> {
-        let is_some = self.is_some();
-        let x = self
-            .get_or_insert_with(|| iter::repeat(F::zero()).take(len).collect())
-            .as_mut();
-
-        if is_some {
-            Ok(x)
-        } else {
-            Err(Ok(x))
-        }
+    fn get_or_initialize(&mut self, len: usize) -> Result<ShardState<'_, F::Elem>, Error> {
+        Ok(match self {
+            Some(x) => ShardState::Present(x.as_mut()),
+            None => ShardState::Missing(
+                self.insert(iter::repeat(F::zero()).take(len).collect())
+                    .as_mut(),
+            ),
+        })
     }
 }
 
@@ -182,19 +245,20 @@ impl<F: Field, T: AsRef<[F::Elem]> + AsMut<[F::Elem]>> ReconstructShard<F> for (
         }
     }
 
-    fn get_or_initialize(
-        &mut self,
-        len: usize,
-    ) -> Result<&mut [F::Elem], Result<&mut [F::Elem], Error>> {
+    fn get_or_initialize(&mut self, len: usize) -> Result<ShardState<'_, F::Elem>, Error> {
         let x = self.0.as_mut();
         if x.len() == len {
             if self.1 {
-                Ok(x)
+                Ok(ShardState::Present(x))
             } else {
-                Err(Ok(x))
+                Ok(ShardState::Missing(x))
             }
         } else {
-            Err(Err(Error::IncorrectShardSize))
+            Err(Error::IncorrectShardSize {
+                index: 0,
+                expected: len,
+                got: x.len(),
+            })
         }
     }
 }
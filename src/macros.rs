@@ -145,19 +145,30 @@ macro_rules! check_slices {
     ) => {{
         let size = $slices[0].as_ref().len();
         if size == 0 {
-            return Err(Error::EmptyShard);
+            return Err(Error::EmptyShard { index: 0 });
         }
-        for slice in $slices.iter() {
-            if slice.as_ref().len() != size {
-                return Err(Error::IncorrectShardSize);
+        for (index, slice) in $slices.iter().enumerate() {
+            let got = slice.as_ref().len();
+            if got != size {
+                return Err(Error::IncorrectShardSize {
+                    index,
+                    expected: size,
+                    got,
+                });
             }
         }
     }};
     (
         single => $slice_left:expr, single => $slice_right:expr
     ) => {{
-        if $slice_left.as_ref().len() != $slice_right.as_ref().len() {
-            return Err(Error::IncorrectShardSize);
+        let expected = $slice_left.as_ref().len();
+        let got = $slice_right.as_ref().len();
+        if expected != got {
+            return Err(Error::IncorrectShardSize {
+                index: 0,
+                expected,
+                got,
+            });
         }
     }};
     (
@@ -171,9 +182,26 @@ macro_rules! check_slices {
         multi => $slices_left:expr, multi => $slices_right:expr
     ) => {{
         check_slices!(multi => $slices_left);
-        check_slices!(multi => $slices_right);
 
-        check_slices!(single => $slices_left[0], single => $slices_right[0]);
+        // The right-hand slices follow the left-hand ones, e.g. the parity
+        // shards follow the data shards.
+        let size = $slices_left[0].as_ref().len();
+        let offset = $slices_left.len();
+        for (index, slice) in $slices_right.iter().enumerate() {
+            let got = slice.as_ref().len();
+            if got == 0 {
+                return Err(Error::EmptyShard {
+                    index: offset + index,
+                });
+            }
+            if got != size {
+                return Err(Error::IncorrectShardSize {
+                    index: offset + index,
+                    expected: size,
+                    got,
+                });
+            }
+        }
     }}
 }
 
@@ -182,64 +210,69 @@ macro_rules! check_slice_index {
         all => $codec:expr, $index:expr
     ) => {{
         if $index >= $codec.total_shard_count {
-            return Err(Error::InvalidIndex);
+            return Err(Error::InvalidIndex {
+                index: $index,
+                count: $codec.total_shard_count,
+            });
         }
     }};
     (
         data => $codec:expr, $index:expr
     ) => {{
         if $index >= $codec.data_shard_count {
-            return Err(Error::InvalidIndex);
+            return Err(Error::InvalidIndex {
+                index: $index,
+                count: $codec.data_shard_count,
+            });
         }
     }};
     (
         parity => $codec:expr, $index:expr
     ) => {{
         if $index >= $codec.parity_shard_count {
-            return Err(Error::InvalidIndex);
+            return Err(Error::InvalidIndex {
+                index: $index,
+                count: $codec.parity_shard_count,
+            });
         }
     }};
 }
 
 macro_rules! check_piece_count {
     (
-        all => $codec:expr, $pieces:expr
+        @count $expected:expr, $pieces:expr, $too_few:ident, $too_many:ident
     ) => {{
-        if $pieces.as_ref().len() < $codec.total_shard_count {
-            return Err(Error::TooFewShards);
+        let expected = $expected;
+        let got = $pieces.as_ref().len();
+        if got < expected {
+            return Err(Error::$too_few { expected, got });
         }
-        if $pieces.as_ref().len() > $codec.total_shard_count {
-            return Err(Error::TooManyShards);
+        if got > expected {
+            return Err(Error::$too_many { expected, got });
         }
     }};
+    (
+        all => $codec:expr, $pieces:expr
+    ) => {
+        check_piece_count!(@count $codec.total_shard_count, $pieces,
+                           TooFewShards, TooManyShards)
+    };
     (
         data => $codec:expr, $pieces:expr
-    ) => {{
-        if $pieces.as_ref().len() < $codec.data_shard_count {
-            return Err(Error::TooFewDataShards);
-        }
-        if $pieces.as_ref().len() > $codec.data_shard_count {
-            return Err(Error::TooManyDataShards);
-        }
-    }};
+    ) => {
+        check_piece_count!(@count $codec.data_shard_count, $pieces,
+                           TooFewDataShards, TooManyDataShards)
+    };
     (
         parity => $codec:expr, $pieces:expr
-    ) => {{
-        if $pieces.as_ref().len() < $codec.parity_shard_count {
-            return Err(Error::TooFewParityShards);
-        }
-        if $pieces.as_ref().len() > $codec.parity_shard_count {
-            return Err(Error::TooManyParityShards);
-        }
-    }};
+    ) => {
+        check_piece_count!(@count $codec.parity_shard_count, $pieces,
+                           TooFewParityShards, TooManyParityShards)
+    };
     (
         parity_buf => $codec:expr, $pieces:expr
-    ) => {{
-        if $pieces.as_ref().len() < $codec.parity_shard_count {
-            return Err(Error::TooFewBufferShards);
-        }
-        if $pieces.as_ref().len() > $codec.parity_shard_count {
-            return Err(Error::TooManyBufferShards);
-        }
-    }};
+    ) => {
+        check_piece_count!(@count $codec.parity_shard_count, $pieces,
+                           TooFewBufferShards, TooManyBufferShards)
+    };
 }
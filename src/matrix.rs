@@ -1,17 +1,19 @@
-#![allow(dead_code)]
+//! Dense matrices over a `Field`.
+//!
+//! These are the matrices used to build the coding matrix of a `ReedSolomon`
+//! codec, and can be used to design and check other coding matrices.
+//!
+//! As in the rest of the crate, fields are assumed to be of characteristic 2,
+//! i.e. subtraction is the same as addition.
 extern crate alloc;
 
 use alloc::vec;
 use alloc::vec::Vec;
 
+use crate::errors::Error;
 use crate::Field;
 use smallvec::SmallVec;
 
-#[derive(Debug)]
-pub enum Error {
-    SingularMatrix,
-}
-
 macro_rules! acc {
     (
         $m:ident, $r:expr, $c:expr
@@ -20,17 +22,8 @@ macro_rules! acc {
     };
 }
 
-pub fn flatten<T>(m: Vec<Vec<T>>) -> Vec<T> {
-    let mut result: Vec<T> = Vec::with_capacity(m.len() * m[0].len());
-    for row in m {
-        for v in row {
-            result.push(v);
-        }
-    }
-    result
-}
-
-#[derive(PartialEq, Debug, Clone)]
+/// A matrix with elements of `F`, stored row by row.
+#[derive(Debug)]
 pub struct Matrix<F: Field> {
     row_count: usize,
     col_count: usize,
@@ -38,6 +31,24 @@ pub struct Matrix<F: Field> {
                                      // the smallvec can hold a matrix of size up to 32x32 in stack
 }
 
+// Not derived, which would require `F: Clone`.
+impl<F: Field> Clone for Matrix<F> {
+    fn clone(&self) -> Matrix<F> {
+        Matrix {
+            row_count: self.row_count,
+            col_count: self.col_count,
+            data: self.data.clone(),
+        }
+    }
+}
+
+// Not derived, which would require `F: PartialEq`.
+impl<F: Field> PartialEq for Matrix<F> {
+    fn eq(&self, rhs: &Matrix<F>) -> bool {
+        self.row_count == rhs.row_count && self.col_count == rhs.col_count && self.data == rhs.data
+    }
+}
+
 fn calc_matrix_row_start_end(col_count: usize, row: usize) -> (usize, usize) {
     let start = row * col_count;
     let end = start + col_count;
@@ -50,6 +61,7 @@ impl<F: Field> Matrix<F> {
         calc_matrix_row_start_end(self.col_count, row)
     }
 
+    /// Create a matrix of zeros.
     pub fn new(rows: usize, cols: usize) -> Matrix<F> {
         let data = SmallVec::from_vec(vec![F::zero(); rows * cols]);
 
@@ -60,23 +72,24 @@ impl<F: Field> Matrix<F> {
         }
     }
 
-    pub fn new_with_data(init_data: Vec<Vec<F::Elem>>) -> Matrix<F> {
+    /// Create a matrix from a list of rows.
+    ///
+    /// Returns `Error::IncompatibleDimensions` if the rows differ in length.
+    pub fn new_with_data(init_data: Vec<Vec<F::Elem>>) -> Result<Matrix<F>, Error> {
         let rows = init_data.len();
-        let cols = init_data[0].len();
+        let cols = init_data.first().map_or(0, |row| row.len());
 
-        for r in init_data.iter() {
-            if r.len() != cols {
-                panic!("Inconsistent row sizes")
-            }
+        if init_data.iter().any(|row| row.len() != cols) {
+            return Err(Error::IncompatibleDimensions);
         }
 
-        let data = SmallVec::from_vec(flatten(init_data));
+        let data = init_data.into_iter().flatten().collect();
 
-        Matrix {
+        Ok(Matrix {
             row_count: rows,
             col_count: cols,
             data,
-        }
+        })
     }
 
     #[cfg(test)]
@@ -89,9 +102,10 @@ impl<F: Field> Matrix<F> {
             crate::tests::fill_random(v);
         }
 
-        Matrix::new_with_data(vec)
+        Matrix::new_with_data(vec).unwrap()
     }
 
+    /// Create the identity matrix of the given size.
     pub fn identity(size: usize) -> Matrix<F> {
         let mut result = Self::new(size, size);
         for i in 0..size {
@@ -100,50 +114,120 @@ impl<F: Field> Matrix<F> {
         result
     }
 
+    /// Number of columns.
     pub fn col_count(&self) -> usize {
         self.col_count
     }
 
+    /// Number of rows.
     pub fn row_count(&self) -> usize {
         self.row_count
     }
 
+    /// Element at row `r` and column `c`.
+    ///
+    /// # Panics
+    /// Panics if `r` or `c` is out of bounds.
     pub fn get(&self, r: usize, c: usize) -> F::Elem {
+        assert!(r < self.row_count && c < self.col_count);
         acc!(self, r, c).clone()
     }
 
+    /// Set the element at row `r` and column `c`.
+    ///
+    /// # Panics
+    /// Panics if `r` or `c` is out of bounds.
     pub fn set(&mut self, r: usize, c: usize, val: F::Elem) {
+        assert!(r < self.row_count && c < self.col_count);
         acc!(self, r, c) = val;
     }
 
-    pub fn multiply(&self, rhs: &Matrix<F>) -> Matrix<F> {
+    /// Matrix product `self * rhs`.
+    ///
+    /// Returns `Error::IncompatibleDimensions` if the column count of `self`
+    /// differs from the row count of `rhs`.
+    pub fn multiply(&self, rhs: &Matrix<F>) -> Result<Matrix<F>, Error> {
         if self.col_count != rhs.row_count {
-            panic!(
-                "Colomn count on left is different from row count on right, lhs: {}, rhs: {}",
-                self.col_count, rhs.row_count
-            )
+            return Err(Error::IncompatibleDimensions);
         }
         let mut result = Self::new(self.row_count, rhs.col_count);
         for r in 0..self.row_count {
-            for c in 0..rhs.col_count {
-                let mut val = F::zero();
-                for i in 0..self.col_count {
-                    let mul = F::mul(acc!(self, r, i).clone(), acc!(rhs, i, c).clone());
-
-                    val = F::add(val, mul);
-                }
-                acc!(result, r, c) = val;
+            // Row r of the result is the sum of the rows of `rhs`, scaled by
+            // the entries of row r of `self`. Working on whole rows lets the
+            // field use its slice kernels.
+            let (start, end) = result.calc_row_start_end(r);
+            let out = &mut result.data[start..end];
+            for i in 0..self.col_count {
+                F::mul_slice_add(acc!(self, r, i), rhs.get_row(i), out);
             }
         }
-        result
+        Ok(result)
+    }
+
+    /// Add `scale` times row `src` to row `dst`.
+    ///
+    /// # Panics
+    /// Panics if `src == dst`, or either is out of bounds.
+    pub fn add_scaled_row(&mut self, src: usize, dst: usize, scale: F::Elem) {
+        assert_ne!(src, dst);
+        assert!(src < self.row_count && dst < self.row_count);
+
+        let (src_start, src_end) = self.calc_row_start_end(src);
+        let (dst_start, dst_end) = self.calc_row_start_end(dst);
+
+        if src < dst {
+            let (head, tail) = self.data.split_at_mut(dst_start);
+            F::mul_slice_add(scale, &head[src_start..src_end], &mut tail[..dst_end - dst_start]);
+        } else {
+            let (head, tail) = self.data.split_at_mut(src_start);
+            F::mul_slice_add(scale, &tail[..src_end - src_start], &mut head[dst_start..dst_end]);
+        }
     }
 
-    pub fn augment(&self, rhs: &Matrix<F>) -> Matrix<F> {
+    /// Multiply row `r` by `scale`.
+    ///
+    /// # Panics
+    /// Panics if `r` is out of bounds.
+    pub fn scale_row(&mut self, r: usize, scale: F::Elem) {
+        assert!(r < self.row_count);
+
+        let (start, end) = self.calc_row_start_end(r);
+        let row: SmallVec<[F::Elem; 64]> = SmallVec::from_slice(&self.data[start..end]);
+        F::mul_slice(scale, &row, &mut self.data[start..end]);
+    }
+
+    /// Add `scale` times column `src` to column `dst`.
+    ///
+    /// # Panics
+    /// Panics if `src == dst`, or either is out of bounds.
+    pub fn add_scaled_column(&mut self, src: usize, dst: usize, scale: F::Elem) {
+        assert_ne!(src, dst);
+        assert!(src < self.col_count && dst < self.col_count);
+
+        for r in 0..self.row_count {
+            let product = F::mul(acc!(self, r, src), scale);
+            acc!(self, r, dst) = F::add(acc!(self, r, dst), product);
+        }
+    }
+
+    /// Multiply column `c` by `scale`.
+    ///
+    /// # Panics
+    /// Panics if `c` is out of bounds.
+    pub fn scale_column(&mut self, c: usize, scale: F::Elem) {
+        assert!(c < self.col_count);
+
+        for r in 0..self.row_count {
+            acc!(self, r, c) = F::mul(acc!(self, r, c), scale);
+        }
+    }
+
+    /// Matrix with the columns of `rhs` appended to the columns of `self`.
+    ///
+    /// Returns `Error::IncompatibleDimensions` if the row counts differ.
+    pub fn augment(&self, rhs: &Matrix<F>) -> Result<Matrix<F>, Error> {
         if self.row_count != rhs.row_count {
-            panic!(
-                "Matrices do not have the same row count, lhs: {}, rhs: {}",
-                self.row_count, rhs.row_count
-            )
+            return Err(Error::IncompatibleDimensions);
         }
         let mut result = Self::new(self.row_count, self.col_count + rhs.col_count);
         for r in 0..self.row_count {
@@ -156,26 +240,52 @@ impl<F: Field> Matrix<F> {
             }
         }
 
-        result
+        Ok(result)
     }
 
-    pub fn sub_matrix(&self, rmin: usize, cmin: usize, rmax: usize, cmax: usize) -> Matrix<F> {
+    /// The part of the matrix in rows `rmin..rmax` and columns `cmin..cmax`.
+    ///
+    /// Returns `Error::IncompatibleDimensions` if the ranges are decreasing or
+    /// out of bounds.
+    pub fn sub_matrix(
+        &self,
+        rmin: usize,
+        cmin: usize,
+        rmax: usize,
+        cmax: usize,
+    ) -> Result<Matrix<F>, Error> {
+        if rmin > rmax || rmax > self.row_count || cmin > cmax || cmax > self.col_count {
+            return Err(Error::IncompatibleDimensions);
+        }
+
         let mut result = Self::new(rmax - rmin, cmax - cmin);
         for r in rmin..rmax {
             for c in cmin..cmax {
                 acc!(result, r - rmin, c - cmin) = acc!(self, r, c).clone();
             }
         }
-        result
+        Ok(result)
     }
 
+    /// The elements of row `row`.
+    ///
+    /// # Panics
+    /// Panics if `row` is out of bounds.
     pub fn get_row(&self, row: usize) -> &[F::Elem] {
+        assert!(row < self.row_count);
+
         let (start, end) = self.calc_row_start_end(row);
 
         &self.data[start..end]
     }
 
+    /// Swap rows `r1` and `r2`.
+    ///
+    /// # Panics
+    /// Panics if `r1` or `r2` is out of bounds.
     pub fn swap_rows(&mut self, r1: usize, r2: usize) {
+        assert!(r1 < self.row_count && r2 < self.row_count);
+
         let (r1_s, _) = self.calc_row_start_end(r1);
         let (r2_s, _) = self.calc_row_start_end(r2);
 
@@ -188,11 +298,126 @@ impl<F: Field> Matrix<F> {
         }
     }
 
+    /// Swap columns `c1` and `c2`.
+    ///
+    /// # Panics
+    /// Panics if `c1` or `c2` is out of bounds.
+    pub fn swap_columns(&mut self, c1: usize, c2: usize) {
+        assert!(c1 < self.col_count && c2 < self.col_count);
+
+        if c1 != c2 {
+            for r in 0..self.row_count {
+                let (start, _) = self.calc_row_start_end(r);
+                self.data.swap(start + c1, start + c2);
+            }
+        }
+    }
+
+    /// Whether the row count equals the column count.
     pub fn is_square(&self) -> bool {
         self.row_count == self.col_count
     }
 
+    /// The transpose of the matrix.
+    pub fn transpose(&self) -> Matrix<F> {
+        let mut result = Self::new(self.col_count, self.row_count);
+        for r in 0..self.row_count {
+            for c in 0..self.col_count {
+                acc!(result, c, r) = acc!(self, r, c);
+            }
+        }
+        result
+    }
+
+    // Brings a copy of the matrix into row echelon form, returning it along
+    // with the pivot column of each non-zero row.
+    fn row_echelon(&self) -> (Matrix<F>, Vec<usize>) {
+        let mut work = self.clone();
+        let mut pivots = Vec::new();
+
+        for c in 0..self.col_count {
+            let r = pivots.len();
+            if r == self.row_count {
+                break;
+            }
+            let pivot_row = match (r..self.row_count).find(|&i| acc!(work, i, c) != F::zero()) {
+                Some(i) => i,
+                None => continue,
+            };
+            work.swap_rows(r, pivot_row);
+
+            let pivot = acc!(work, r, c);
+            for r_below in r + 1..self.row_count {
+                if acc!(work, r_below, c) != F::zero() {
+                    let scale = F::div(acc!(work, r_below, c), pivot);
+                    work.add_scaled_row(r, r_below, scale);
+                }
+            }
+            pivots.push(c);
+        }
+
+        (work, pivots)
+    }
+
+    /// The rank of the matrix, i.e. the number of linearly independent rows.
+    pub fn rank(&self) -> usize {
+        self.row_echelon().1.len()
+    }
+
+    /// The determinant of the matrix.
+    ///
+    /// Returns `Error::NotSquare` for a non-square matrix.
+    pub fn determinant(&self) -> Result<F::Elem, Error> {
+        if !self.is_square() {
+            return Err(Error::NotSquare);
+        }
+
+        let (work, pivots) = self.row_echelon();
+        if pivots.len() < self.row_count {
+            return Ok(F::zero());
+        }
+
+        // Row swaps only flip the sign, which does nothing in characteristic 2.
+        let mut result = F::one();
+        for i in 0..self.row_count {
+            result = F::mul(result, acc!(work, i, i));
+        }
+        Ok(result)
+    }
+
+    /// Solve `self * x = rhs` for `x`, where `rhs` may have any number of
+    /// columns.
+    ///
+    /// Returns `Error::NotSquare` if `self` is not square,
+    /// `Error::IncompatibleDimensions` if the row counts differ and
+    /// `Error::SingularMatrix` if `self` is not invertible.
+    pub fn solve(&self, rhs: &Matrix<F>) -> Result<Matrix<F>, Error> {
+        if !self.is_square() {
+            return Err(Error::NotSquare);
+        }
+        if self.row_count != rhs.row_count {
+            return Err(Error::IncompatibleDimensions);
+        }
+
+        let size = self.row_count;
+
+        let mut work = self.augment(rhs)?;
+        work.gaussian_elim()?;
+
+        work.sub_matrix(0, size, size, size + rhs.col_count)
+    }
+
+    /// Reduce the matrix in place, so that its leading square part becomes the
+    /// identity matrix.
+    ///
+    /// Returns `Error::IncompatibleDimensions` if the matrix has more rows
+    /// than columns, and `Error::SingularMatrix` if the leading square part is
+    /// not invertible, in which case the matrix is left partially reduced.
     pub fn gaussian_elim(&mut self) -> Result<(), Error> {
+        if self.row_count > self.col_count {
+            return Err(Error::IncompatibleDimensions);
+        }
+
         for r in 0..self.row_count {
             if acc!(self, r, r) == F::zero() {
                 for r_below in r + 1..self.row_count {
@@ -209,9 +434,7 @@ impl<F: Field> Matrix<F> {
             // Scale to 1.
             if acc!(self, r, r) != F::one() {
                 let scale = F::div(F::one(), acc!(self, r, r).clone());
-                for c in 0..self.col_count {
-                    acc!(self, r, c) = F::mul(scale, acc!(self, r, c).clone());
-                }
+                self.scale_row(r, scale);
             }
             // Make everything below the 1 be a 0 by subtracting
             // a multiple of it.  (Subtraction and addition are
@@ -219,12 +442,7 @@ impl<F: Field> Matrix<F> {
             for r_below in r + 1..self.row_count {
                 if acc!(self, r_below, r) != F::zero() {
                     let scale = acc!(self, r_below, r).clone();
-                    for c in 0..self.col_count {
-                        acc!(self, r_below, c) = F::add(
-                            acc!(self, r_below, c).clone(),
-                            F::mul(scale, acc!(self, r, c).clone()),
-                        );
-                    }
+                    self.add_scaled_row(r, r_below, scale);
                 }
             }
         }
@@ -234,45 +452,51 @@ impl<F: Field> Matrix<F> {
             for r_above in 0..d {
                 if acc!(self, r_above, d) != F::zero() {
                     let scale = acc!(self, r_above, d).clone();
-                    for c in 0..self.col_count {
-                        acc!(self, r_above, c) = F::add(
-                            acc!(self, r_above, c).clone(),
-                            F::mul(scale, acc!(self, d, c).clone()),
-                        );
-                    }
+                    self.add_scaled_row(d, r_above, scale);
                 }
             }
         }
         Ok(())
     }
 
+    /// The inverse of the matrix.
+    ///
+    /// Returns `Error::NotSquare` for a non-square matrix and
+    /// `Error::SingularMatrix` if the matrix is not invertible.
     pub fn invert(&self) -> Result<Matrix<F>, Error> {
         if !self.is_square() {
-            panic!("Trying to invert a non-square matrix")
+            return Err(Error::NotSquare);
         }
 
         let row_count = self.row_count;
         let col_count = self.col_count;
 
-        let mut work = self.augment(&Self::identity(row_count));
+        let mut work = self.augment(&Self::identity(row_count))?;
         work.gaussian_elim()?;
 
-        Ok(work.sub_matrix(0, row_count, col_count, col_count * 2))
+        work.sub_matrix(0, row_count, col_count, col_count * 2)
     }
 
-    pub fn vandermonde(rows: usize, cols: usize) -> Matrix<F> {
+    /// The Vandermonde matrix with the rows `[1, a, a^2, ...]` for the first
+    /// `rows` elements `a` of the field.
+    ///
+    /// Any `cols` of the rows are linearly independent.
+    ///
+    /// Returns `Error::InvalidFieldElement` if `rows` is greater than the order
+    /// of the field.
+    pub fn vandermonde(rows: usize, cols: usize) -> Result<Matrix<F>, Error> {
         let mut result = Self::new(rows, cols);
 
         for r in 0..rows {
             // doesn't matter what `r_a` is as long as it's unique.
             // then the vandermonde matrix is invertible.
-            let r_a = F::nth(r);
+            let r_a = F::try_nth(r)?;
             for c in 0..cols {
                 acc!(result, r, c) = F::exp(r_a, c);
             }
         }
 
-        result
+        Ok(result)
     }
 }
 
@@ -282,7 +506,7 @@ mod tests {
 
     use alloc::vec;
 
-    use super::Matrix;
+    use super::{Error, Matrix};
     use crate::galois_8;
 
     macro_rules! matrix {
@@ -291,7 +515,7 @@ mod tests {
                 [ $( $x:expr ),+ ]
             ),*
         ) => (
-            Matrix::<galois_8::Field>::new_with_data(vec![ $( vec![$( $x ),*] ),* ])
+            Matrix::<galois_8::Field>::new_with_data(vec![ $( vec![$( $x ),*] ),* ]).unwrap()
         );
         ($rows:expr, $cols:expr) => (Matrix::new($rows, $cols));
     }
@@ -339,27 +563,56 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
     fn test_inconsistent_row_sizes() {
-        matrix!([1, 0, 0], [0, 1], [0, 0, 1]);
+        assert_eq!(
+            Error::IncompatibleDimensions,
+            Matrix::<galois_8::Field>::new_with_data(vec![
+                vec![1, 0, 0],
+                vec![0, 1],
+                vec![0, 0, 1]
+            ])
+            .unwrap_err()
+        );
+        assert_eq!(
+            0,
+            Matrix::<galois_8::Field>::new_with_data(vec![])
+                .unwrap()
+                .row_count()
+        );
     }
 
     #[test]
-    #[should_panic]
     fn test_incompatible_multiply() {
         let m1 = matrix!([0, 1], [0, 1], [0, 1]);
         let m2 = matrix!([0, 1, 2]);
 
-        m1.multiply(&m2);
+        assert_eq!(Error::IncompatibleDimensions, m1.multiply(&m2).unwrap_err());
     }
 
     #[test]
-    #[should_panic]
     fn test_incompatible_augment() {
         let m1 = matrix!([0, 1]);
         let m2 = matrix!([0, 1], [2, 3]);
 
-        m1.augment(&m2);
+        assert_eq!(Error::IncompatibleDimensions, m1.augment(&m2).unwrap_err());
+        assert_eq!(
+            matrix!([0, 1, 0, 1]),
+            m1.augment(&m2.sub_matrix(0, 0, 1, 2).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sub_matrix() {
+        let m = matrix!([1, 2, 3], [4, 5, 6]);
+        assert_eq!(matrix!([5, 6]), m.sub_matrix(1, 1, 2, 3).unwrap());
+        assert_eq!(
+            Error::IncompatibleDimensions,
+            m.sub_matrix(0, 0, 3, 3).unwrap_err()
+        );
+        assert_eq!(
+            Error::IncompatibleDimensions,
+            m.sub_matrix(0, 2, 2, 1).unwrap_err()
+        );
     }
 
     #[test]
@@ -373,7 +626,7 @@ mod tests {
     fn test_matrix_multiply() {
         let m1 = matrix!([1, 2], [3, 4]);
         let m2 = matrix!([5, 6], [7, 8]);
-        let actual = m1.multiply(&m2);
+        let actual = m1.multiply(&m2).unwrap();
         let expect = matrix!([11, 22], [19, 42]);
         assert_eq!(actual, expect);
     }
@@ -411,10 +664,41 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
     fn test_matrix_inverse_non_square() {
         // Test case with a non-square matrix.
-        matrix!([56, 23], [3, 100], [45, 201]).invert().unwrap();
+        assert_eq!(
+            Error::NotSquare,
+            matrix!([56, 23], [3, 100], [45, 201]).invert().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_gaussian_elim() {
+        let mut m = matrix!([2, 4, 1], [3, 1, 5]);
+        let orig = m.clone();
+        m.gaussian_elim().unwrap();
+        assert_eq!(Matrix::identity(2), m.sub_matrix(0, 0, 2, 2).unwrap());
+        // The last column is the solution of the leading square part.
+        let x = m.sub_matrix(0, 2, 2, 3).unwrap();
+        let a = orig.sub_matrix(0, 0, 2, 2).unwrap();
+        assert_eq!(orig.sub_matrix(0, 2, 2, 3).unwrap(), a.multiply(&x).unwrap());
+
+        assert_eq!(
+            Error::IncompatibleDimensions,
+            matrix!([1, 2], [3, 4], [5, 6]).gaussian_elim().unwrap_err()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_row_out_of_bounds() {
+        matrix!([1, 2], [3, 4]).get_row(2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swap_rows_out_of_bounds() {
+        matrix!([1, 2], [3, 4]).swap_rows(0, 2);
     }
 
     #[test]
@@ -422,4 +706,94 @@ mod tests {
     fn test_matrix_inverse_singular() {
         matrix!([4, 2], [12, 6]).invert().unwrap();
     }
+
+    #[test]
+    fn test_matrix_transpose() {
+        let m = matrix!([1, 2, 3], [4, 5, 6]);
+        let expect = matrix!([1, 4], [2, 5], [3, 6]);
+        assert_eq!(expect, m.transpose());
+        assert_eq!(m, m.transpose().transpose());
+    }
+
+    #[test]
+    fn test_matrix_column_operations() {
+        let mut m = matrix!([1, 2, 3], [4, 5, 6]);
+        m.swap_columns(0, 2);
+        assert_eq!(matrix!([3, 2, 1], [6, 5, 4]), m);
+
+        m.scale_column(1, 2);
+        assert_eq!(matrix!([3, 4, 1], [6, 10, 4]), m);
+
+        // Column operations are row operations on the transpose
+        let mut t = m.transpose();
+        m.add_scaled_column(0, 2, 7);
+        t.add_scaled_row(0, 2, 7);
+        assert_eq!(m, t.transpose());
+
+        m.scale_row(1, 3);
+        t.scale_column(1, 3);
+        assert_eq!(m, t.transpose());
+    }
+
+    #[test]
+    fn test_matrix_rank() {
+        assert_eq!(3, matrix!([56, 23, 98], [3, 100, 200], [45, 201, 123]).rank());
+        assert_eq!(1, matrix!([4, 2], [12, 6]).rank());
+        assert_eq!(0, matrix!([0, 0, 0], [0, 0, 0]).rank());
+        assert_eq!(2, matrix!([0, 1, 2], [0, 3, 4], [0, 1, 2]).rank());
+        assert_eq!(
+            3,
+            Matrix::<galois_8::Field>::vandermonde(5, 3)
+                .unwrap()
+                .rank()
+        );
+        assert_eq!(
+            Err(Error::InvalidFieldElement {
+                index: 256,
+                order: 256
+            }),
+            Matrix::<galois_8::Field>::vandermonde(257, 3)
+        );
+    }
+
+    #[test]
+    fn test_matrix_determinant() {
+        assert_eq!(Ok(1), Matrix::<galois_8::Field>::identity(4).determinant());
+        assert_eq!(Ok(0), matrix!([4, 2], [12, 6]).determinant());
+        // ad + bc
+        assert_eq!(
+            Ok(galois_8::add(galois_8::mul(1, 4), galois_8::mul(2, 3))),
+            matrix!([1, 2], [3, 4]).determinant()
+        );
+        assert_eq!(Err(Error::NotSquare), matrix!([1, 2]).determinant());
+
+        // det(AB) = det(A) det(B)
+        for _ in 0..10 {
+            let a = Matrix::<galois_8::Field>::make_random(5);
+            let b = Matrix::<galois_8::Field>::make_random(5);
+            assert_eq!(
+                a.multiply(&b).unwrap().determinant().unwrap(),
+                galois_8::mul(a.determinant().unwrap(), b.determinant().unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn test_matrix_solve() {
+        let a = matrix!([56, 23, 98], [3, 100, 200], [45, 201, 123]);
+        let b = matrix!([1, 2], [3, 4], [5, 6]);
+        let x = a.solve(&b).unwrap();
+        assert_eq!(b, a.multiply(&x).unwrap());
+        assert_eq!(a.invert().unwrap().multiply(&b).unwrap(), x);
+
+        assert_eq!(
+            Error::SingularMatrix,
+            matrix!([4, 2], [12, 6]).solve(&matrix!([1], [2])).unwrap_err()
+        );
+        assert_eq!(Error::NotSquare, matrix!([4, 2]).solve(&matrix!([1])).unwrap_err());
+        assert_eq!(
+            Error::IncompatibleDimensions,
+            a.solve(&matrix!([1], [2])).unwrap_err()
+        );
+    }
 }
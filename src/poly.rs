@@ -0,0 +1,344 @@
+//! Polynomials over a `Field`.
+//!
+//! As in the rest of the crate, fields are assumed to be of characteristic 2,
+//! i.e. subtraction is the same as addition.
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::ops::{Add, Mul, Sub};
+
+use crate::Field;
+
+/// A polynomial with coefficients in `F`.
+///
+/// Coefficients are stored lowest degree first, without trailing zeros, so the
+/// zero polynomial has no coefficients at all.
+///
+/// ```
+/// use reed_solomon_erasure::galois_8::Field;
+/// use reed_solomon_erasure::poly::Poly;
+///
+/// // x^2 + 3
+/// let p = Poly::<Field>::new(vec![3, 0, 1]);
+/// let q = Poly::interpolate(&[(1, p.evaluate(1)), (2, p.evaluate(2)), (5, p.evaluate(5))])
+///     .unwrap();
+/// assert_eq!(p, q);
+/// ```
+#[derive(PartialEq, Debug)]
+pub struct Poly<F: Field> {
+    coeffs: Vec<F::Elem>,
+}
+
+// Not derived, which would require `F: Clone`.
+impl<F: Field> Clone for Poly<F> {
+    fn clone(&self) -> Poly<F> {
+        Poly {
+            coeffs: self.coeffs.clone(),
+        }
+    }
+}
+
+impl<F: Field> Poly<F> {
+    /// Create a polynomial from its coefficients, lowest degree first.
+    pub fn new(mut coeffs: Vec<F::Elem>) -> Poly<F> {
+        while coeffs.last() == Some(&F::zero()) {
+            coeffs.pop();
+        }
+        Poly { coeffs }
+    }
+
+    /// The zero polynomial.
+    pub fn zero() -> Poly<F> {
+        Poly { coeffs: Vec::new() }
+    }
+
+    /// The constant polynomial `c`.
+    pub fn constant(c: F::Elem) -> Poly<F> {
+        Poly::new(vec![c])
+    }
+
+    /// The polynomial `c * x^n`.
+    pub fn monomial(c: F::Elem, n: usize) -> Poly<F> {
+        let mut coeffs = vec![F::zero(); n + 1];
+        coeffs[n] = c;
+        Poly::new(coeffs)
+    }
+
+    /// The coefficients, lowest degree first.
+    pub fn coefficients(&self) -> &[F::Elem] {
+        &self.coeffs
+    }
+
+    /// The degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Whether this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// The coefficient of the highest-degree term, or zero for the zero
+    /// polynomial.
+    pub fn leading_coefficient(&self) -> F::Elem {
+        self.coeffs.last().cloned().unwrap_or_else(F::zero)
+    }
+
+    /// Evaluate the polynomial at `x`.
+    pub fn evaluate(&self, x: F::Elem) -> F::Elem {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, &c| F::add(F::mul(acc, x), c))
+    }
+
+    /// Multiply every coefficient by `c`.
+    pub fn scale(&self, c: F::Elem) -> Poly<F> {
+        let mut coeffs = vec![F::zero(); self.coeffs.len()];
+        F::mul_slice(c, &self.coeffs, &mut coeffs);
+        Poly::new(coeffs)
+    }
+
+    /// Quotient and remainder of the division by `divisor`.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn divmod(&self, divisor: &Poly<F>) -> (Poly<F>, Poly<F>) {
+        let divisor_degree = match divisor.degree() {
+            Some(d) => d,
+            None => panic!("Divisor is the zero polynomial"),
+        };
+        if self.coeffs.len() <= divisor_degree {
+            return (Poly::zero(), self.clone());
+        }
+
+        let lead_inv = F::div(F::one(), divisor.leading_coefficient());
+        let mut rem = self.coeffs.clone();
+        let mut quot = vec![F::zero(); rem.len() - divisor_degree];
+
+        for i in (0..quot.len()).rev() {
+            let q = F::mul(rem[i + divisor_degree], lead_inv);
+            quot[i] = q;
+            // Subtract `q * x^i * divisor`, which is adding it.
+            F::mul_slice_add(q, &divisor.coeffs, &mut rem[i..=i + divisor_degree]);
+        }
+        rem.truncate(divisor_degree);
+
+        (Poly::new(quot), Poly::new(rem))
+    }
+
+    /// The formal derivative.
+    pub fn derivative(&self) -> Poly<F> {
+        let coeffs = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &c)| times::<F>(c, i))
+            .collect();
+        Poly::new(coeffs)
+    }
+
+    /// The polynomial of lowest degree through the given `(x, y)` points.
+    ///
+    /// Returns `None` if two points share the same `x`.
+    pub fn interpolate(points: &[(F::Elem, F::Elem)]) -> Option<Poly<F>> {
+        // Newton's divided differences.
+        let mut diffs: Vec<F::Elem> = points.iter().map(|&(_, y)| y).collect();
+        for j in 1..points.len() {
+            for i in (j..points.len()).rev() {
+                let dx = F::add(points[i].0, points[i - j].0);
+                if dx == F::zero() {
+                    return None;
+                }
+                diffs[i] = F::div(F::add(diffs[i], diffs[i - 1]), dx);
+            }
+        }
+
+        // Horner's scheme on the Newton form, from the innermost term out.
+        let mut coeffs = Vec::with_capacity(points.len());
+        for (i, &d) in diffs.iter().enumerate().rev() {
+            // Multiply by `x - x_i`.
+            let x_i = points[i].0;
+            coeffs.insert(0, F::zero());
+            for k in 0..coeffs.len() - 1 {
+                let c = F::mul(coeffs[k + 1], x_i);
+                coeffs[k] = F::add(coeffs[k], c);
+            }
+            coeffs[0] = F::add(coeffs[0], d);
+        }
+
+        Some(Poly::new(coeffs))
+    }
+}
+
+// `c` added to itself `n` times.
+fn times<F: Field>(c: F::Elem, mut n: usize) -> F::Elem {
+    let mut result = F::zero();
+    let mut c = c;
+    while n > 0 {
+        if n & 1 == 1 {
+            result = F::add(result, c);
+        }
+        c = F::add(c, c);
+        n >>= 1;
+    }
+    result
+}
+
+impl<'a, F: Field> Add for &'a Poly<F> {
+    type Output = Poly<F>;
+
+    fn add(self, rhs: &'a Poly<F>) -> Poly<F> {
+        let (long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        let mut coeffs = long.coeffs.clone();
+        for (o, &c) in coeffs.iter_mut().zip(short.coeffs.iter()) {
+            *o = F::add(*o, c);
+        }
+        Poly::new(coeffs)
+    }
+}
+
+impl<'a, F: Field> Sub for &'a Poly<F> {
+    type Output = Poly<F>;
+
+    fn sub(self, rhs: &'a Poly<F>) -> Poly<F> {
+        // Same as addition in characteristic 2.
+        Add::add(self, rhs)
+    }
+}
+
+impl<'a, F: Field> Mul for &'a Poly<F> {
+    type Output = Poly<F>;
+
+    fn mul(self, rhs: &'a Poly<F>) -> Poly<F> {
+        if self.is_zero() || rhs.is_zero() {
+            return Poly::zero();
+        }
+
+        let mut coeffs = vec![F::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, &c) in self.coeffs.iter().enumerate() {
+            F::mul_slice_add(c, &rhs.coeffs, &mut coeffs[i..i + rhs.coeffs.len()]);
+        }
+        Poly::new(coeffs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::Poly;
+    use crate::tests::fill_random;
+    use crate::{galois_16, galois_8};
+
+    type P = Poly<galois_8::Field>;
+
+    fn random_poly(len: usize) -> P {
+        let mut coeffs = vec![0; len];
+        fill_random(&mut coeffs);
+        Poly::new(coeffs)
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(P::zero(), P::new(vec![0, 0, 0]));
+        assert_eq!(None, P::zero().degree());
+        assert_eq!(Some(1), P::new(vec![1, 2, 0]).degree());
+        assert_eq!(P::monomial(5, 2), P::new(vec![0, 0, 5]));
+        assert!(P::monomial(0, 2).is_zero());
+    }
+
+    #[test]
+    fn test_add_mul() {
+        // (x + 1)(x + 1) = x^2 + 1
+        let p = P::new(vec![1, 1]);
+        assert_eq!(P::new(vec![1, 0, 1]), &p * &p);
+        assert_eq!(P::zero(), &p + &p);
+        assert_eq!(P::zero(), &p * &P::zero());
+
+        for _ in 0..20 {
+            let a = random_poly(7);
+            let b = random_poly(4);
+            let x = rand::random::<u8>();
+            assert_eq!(
+                galois_8::add(a.evaluate(x), b.evaluate(x)),
+                (&a + &b).evaluate(x)
+            );
+            assert_eq!(
+                galois_8::mul(a.evaluate(x), b.evaluate(x)),
+                (&a * &b).evaluate(x)
+            );
+        }
+    }
+
+    #[test]
+    fn test_divmod() {
+        for _ in 0..20 {
+            let a = random_poly(10);
+            let b = random_poly(4);
+            if b.is_zero() {
+                continue;
+            }
+            let (q, r) = a.divmod(&b);
+            assert!(r.degree() < b.degree());
+            assert_eq!(a, &(&q * &b) + &r);
+        }
+
+        let a = P::new(vec![1, 2]);
+        assert_eq!((P::zero(), a.clone()), a.divmod(&P::new(vec![3, 4, 5])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_divmod_by_zero() {
+        P::new(vec![1, 2]).divmod(&P::zero());
+    }
+
+    #[test]
+    fn test_derivative() {
+        // d/dx (x^3 + x^2 + 5x + 7) = 3x^2 + 2x + 5 = x^2 + 5
+        let p = P::new(vec![7, 5, 1, 1]);
+        assert_eq!(P::new(vec![5, 0, 1]), p.derivative());
+
+        // Product rule
+        let a = random_poly(6);
+        let b = random_poly(5);
+        assert_eq!(
+            (&a * &b).derivative(),
+            &(&a.derivative() * &b) + &(&a * &b.derivative())
+        );
+    }
+
+    #[test]
+    fn test_interpolate() {
+        for len in 0..20 {
+            let p = random_poly(len);
+            let points: Vec<_> = (1..=len as u8).map(|x| (x, p.evaluate(x))).collect();
+            assert_eq!(p, P::interpolate(&points).unwrap());
+        }
+
+        assert_eq!(None, P::interpolate(&[(1, 2), (3, 4), (1, 5)]));
+    }
+
+    #[test]
+    fn test_interpolate_galois_16() {
+        let p = Poly::<galois_16::Field>::new(vec![[1, 2], [3, 4], [5, 6]]);
+        let points: Vec<_> = [[0, 1], [1, 0], [7, 7]]
+            .iter()
+            .map(|&x| (x, p.evaluate(x)))
+            .collect();
+        assert_eq!(p, Poly::interpolate(&points).unwrap());
+    }
+}
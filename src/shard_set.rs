@@ -0,0 +1,236 @@
+use core::fmt;
+use core::iter::FromIterator;
+
+use smallvec::SmallVec;
+
+/// A set of shard indices, stored as a bitset.
+///
+/// Used to describe erasure patterns, e.g. which shards are present.
+/// Sets of indices below 256 are stored inline, without allocating.
+///
+/// ```
+/// use reed_solomon_erasure::ShardSet;
+///
+/// let mut missing: ShardSet = [1, 4].iter().cloned().collect();
+/// assert!(missing.insert(9));
+/// assert!(!missing.insert(4));
+/// assert_eq!(vec![1, 4, 9], missing.iter().collect::<Vec<_>>());
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct ShardSet {
+    // Bit `i % 64` of word `i / 64` is set if `i` is in the set.
+    // Trailing zero words are dropped, so equal sets compare and hash equal.
+    words: SmallVec<[u64; 4]>,
+}
+
+impl ShardSet {
+    /// Creates an empty set.
+    pub fn new() -> ShardSet {
+        ShardSet {
+            words: SmallVec::new(),
+        }
+    }
+
+    /// Creates the set of indices `0..n`.
+    pub fn full(n: usize) -> ShardSet {
+        let mut words: SmallVec<[u64; 4]> = SmallVec::from_elem(u64::MAX, n / 64);
+        if n % 64 != 0 {
+            words.push(u64::MAX >> (64 - n % 64));
+        }
+        ShardSet { words }
+    }
+
+    /// Creates a set from its bitset representation: index `i` is in the set
+    /// if bit `i % 64` of `words[i / 64]` is set.
+    pub fn from_words(words: &[u64]) -> ShardSet {
+        let mut set = ShardSet {
+            words: SmallVec::from_slice(words),
+        };
+        set.trim();
+        set
+    }
+
+    /// The bitset representation of the set, see `from_words`.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Adds an index to the set, returning whether it was not already present.
+    pub fn insert(&mut self, index: usize) -> bool {
+        let (word, bit) = (index / 64, 1 << (index % 64));
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let inserted = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        inserted
+    }
+
+    /// Removes an index from the set, returning whether it was present.
+    pub fn remove(&mut self, index: usize) -> bool {
+        if !self.contains(index) {
+            return false;
+        }
+        self.words[index / 64] &= !(1 << (index % 64));
+        self.trim();
+        true
+    }
+
+    /// Whether the index is in the set.
+    pub fn contains(&self, index: usize) -> bool {
+        match self.words.get(index / 64) {
+            Some(word) => word & (1 << (index % 64)) != 0,
+            None => false,
+        }
+    }
+
+    /// The number of indices in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// The largest index in the set.
+    pub fn last(&self) -> Option<usize> {
+        let last = *self.words.last()?;
+        Some(self.words.len() * 64 - 1 - last.leading_zeros() as usize)
+    }
+
+    /// Removes all indices.
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    /// Iterates over the indices in ascending order.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            words: &self.words,
+            next_word: 0,
+            word: 0,
+        }
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+}
+
+impl fmt::Debug for ShardSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<usize> for ShardSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> ShardSet {
+        let mut set = ShardSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<usize> for ShardSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for index in iter {
+            self.insert(index);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a ShardSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over the indices of a `ShardSet`, in ascending order.
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    words: &'a [u64],
+    next_word: usize,
+    // Bits of the current word not yet yielded.
+    word: u64,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            self.word = *self.words.get(self.next_word)?;
+            self.next_word += 1;
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1;
+        Some((self.next_word - 1) * 64 + bit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::ShardSet;
+
+    #[test]
+    fn test_insert_remove() {
+        let mut set = ShardSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(3));
+        assert!(set.insert(300));
+        assert!(!set.insert(3));
+        assert!(set.contains(3));
+        assert!(set.contains(300));
+        assert!(!set.contains(4));
+        assert!(!set.contains(10_000));
+        assert_eq!(2, set.len());
+        assert_eq!(Some(300), set.last());
+
+        assert!(set.remove(300));
+        assert!(!set.remove(300));
+        assert_eq!(Some(3), set.last());
+        assert_eq!(&[1 << 3], set.words());
+
+        assert!(set.remove(3));
+        assert!(set.is_empty());
+        assert_eq!(None, set.last());
+    }
+
+    #[test]
+    fn test_equality_ignores_capacity() {
+        let mut a: ShardSet = [1, 200].iter().cloned().collect();
+        a.remove(200);
+        let b = ShardSet::from_words(&[2, 0, 0, 0, 0]);
+        assert_eq!(a, b);
+        assert_eq!(b.words(), &[2]);
+    }
+
+    #[test]
+    fn test_iter() {
+        let indices = vec![0, 1, 63, 64, 65, 127, 128, 255, 256, 1000];
+        let set: ShardSet = indices.iter().rev().cloned().collect();
+        assert_eq!(indices, set.iter().collect::<Vec<_>>());
+        assert_eq!(indices.len(), set.len());
+        assert_eq!(None, ShardSet::new().iter().next());
+    }
+
+    #[test]
+    fn test_full() {
+        for &n in [0, 1, 63, 64, 65, 256].iter() {
+            let set = ShardSet::full(n);
+            assert_eq!((0..n).collect::<Vec<_>>(), set.iter().collect::<Vec<_>>());
+        }
+    }
+}
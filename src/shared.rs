@@ -0,0 +1,220 @@
+//! Process-wide registry of codecs, see `ReedSolomon::shared`.
+//!
+//! Libraries that each need e.g. a 10+4 codec over the same field end up with
+//! the same `Arc<ReedSolomon<F>>`, so the coding matrix is built once and the
+//! decode matrix cache is shared.
+//!
+//! The registry keeps every codec it hands out alive until it is evicted. By
+//! default it is unbounded; `set_capacity` bounds the number of codecs it
+//! keeps, evicting the least recently requested ones first. Evicting a codec
+//! only drops the registry's reference, existing handles stay valid.
+use std::any::{Any, TypeId};
+use std::sync::Arc;
+
+use parking_lot::{const_mutex, Mutex};
+
+use crate::core::MatrixKind;
+use crate::errors::Error;
+use crate::{Field, ReedSolomon};
+
+static REGISTRY: Mutex<Registry> = const_mutex(Registry::new());
+
+/// Bounds the number of codecs kept by the registry, or lifts the bound with
+/// `None`. Evicts the least recently requested codecs that are over the new
+/// bound.
+pub fn set_capacity(capacity: Option<usize>) {
+    REGISTRY.lock().set_capacity(capacity)
+}
+
+// Identifies a codec like `ReedSolomon`'s `PartialEq` does, see `MatrixKind`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Key {
+    field: TypeId,
+    data_shards: usize,
+    parity_shards: usize,
+    matrix_kind: MatrixKind,
+}
+
+struct Registry {
+    // Least recently requested first. Processes only use a handful of
+    // codecs, so a linear scan beats hashing.
+    codecs: Vec<(Key, Arc<dyn Any + Send + Sync>)>,
+    capacity: Option<usize>,
+}
+
+impl Registry {
+    const fn new() -> Registry {
+        Registry {
+            codecs: Vec::new(),
+            capacity: None,
+        }
+    }
+
+    fn get<F: Field + 'static>(&mut self, key: Key) -> Option<Arc<ReedSolomon<F>>>
+    where
+        ReedSolomon<F>: Send + Sync,
+    {
+        let pos = self.codecs.iter().position(|(k, _)| *k == key)?;
+        let entry = self.codecs.remove(pos);
+        let codec = entry.1.clone();
+        self.codecs.push(entry);
+        Some(
+            codec
+                .downcast()
+                .expect("codecs are keyed by the type of their field"),
+        )
+    }
+
+    // Returns the codec already registered under `key` if another thread got
+    // there first.
+    fn insert<F: Field + 'static>(
+        &mut self,
+        key: Key,
+        codec: Arc<ReedSolomon<F>>,
+    ) -> Arc<ReedSolomon<F>>
+    where
+        ReedSolomon<F>: Send + Sync,
+    {
+        if let Some(existing) = self.get(key) {
+            return existing;
+        }
+        self.codecs.push((key, codec.clone()));
+        self.evict();
+        codec
+    }
+
+    fn set_capacity(&mut self, capacity: Option<usize>) {
+        self.capacity = capacity;
+        self.evict();
+    }
+
+    fn evict(&mut self) {
+        if let Some(capacity) = self.capacity {
+            let excess = self.codecs.len().saturating_sub(capacity);
+            self.codecs.drain(..excess);
+        }
+    }
+}
+
+// The codec is built without holding the lock, so that building a large one
+// does not block callers asking for other codecs. Two threads may then build
+// the same codec, the first one to insert it wins.
+fn get_or_create<F: Field + 'static>(
+    registry: &Mutex<Registry>,
+    data_shards: usize,
+    parity_shards: usize,
+) -> Result<Arc<ReedSolomon<F>>, Error>
+where
+    ReedSolomon<F>: Send + Sync,
+{
+    let key = Key {
+        field: TypeId::of::<F>(),
+        data_shards,
+        parity_shards,
+        // The kind `ReedSolomon::new` builds.
+        matrix_kind: MatrixKind::Vandermonde,
+    };
+    if let Some(codec) = registry.lock().get(key) {
+        return Ok(codec);
+    }
+
+    let codec = Arc::new(ReedSolomon::new(data_shards, parity_shards)?);
+    Ok(registry.lock().insert(key, codec))
+}
+
+impl<F: Field + 'static> ReedSolomon<F>
+where
+    ReedSolomon<F>: Send + Sync,
+{
+    /// Returns the codec for the given shard counts from the process-wide
+    /// registry, creating it on first use. See the `shared` module.
+    ///
+    /// Returns the same errors as `new`, failures are not cached.
+    pub fn shared(data_shards: usize, parity_shards: usize) -> Result<Arc<ReedSolomon<F>>, Error> {
+        get_or_create(&REGISTRY, data_shards, parity_shards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+    use std::sync::Arc;
+
+    use parking_lot::Mutex;
+
+    use super::{get_or_create, Key, Registry};
+    use crate::core::MatrixKind;
+    use crate::{galois_16, galois_8, ErrorKind, ReedSolomon};
+
+    #[test]
+    fn test_deduplicates() {
+        let registry = Mutex::new(Registry::new());
+
+        let a = get_or_create::<galois_8::Field>(&registry, 10, 4).unwrap();
+        let b = get_or_create::<galois_8::Field>(&registry, 10, 4).unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+
+        // A codec built concurrently loses to the registered one.
+        let key = Key {
+            field: TypeId::of::<galois_8::Field>(),
+            data_shards: 10,
+            parity_shards: 4,
+            matrix_kind: MatrixKind::Vandermonde,
+        };
+        let built = Arc::new(ReedSolomon::new(10, 4).unwrap());
+        assert!(Arc::ptr_eq(&a, &registry.lock().insert(key, built)));
+
+        let c = get_or_create::<galois_8::Field>(&registry, 10, 3).unwrap();
+        assert_eq!(10, c.data_shard_count());
+        assert_eq!(3, c.parity_shard_count());
+
+        let d = get_or_create::<galois_16::Field>(&registry, 10, 4).unwrap();
+        assert_eq!(14, d.total_shard_count());
+        assert_eq!(3, registry.lock().codecs.len());
+
+        assert_eq!(
+            ErrorKind::TooFewParityShards,
+            get_or_create::<galois_8::Field>(&registry, 10, 0)
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(3, registry.lock().codecs.len());
+    }
+
+    #[test]
+    fn test_capacity() {
+        let registry = Mutex::new(Registry::new());
+        registry.lock().set_capacity(Some(2));
+
+        let a = get_or_create::<galois_8::Field>(&registry, 1, 1).unwrap();
+        get_or_create::<galois_8::Field>(&registry, 2, 1).unwrap();
+        // Now the most recently requested.
+        get_or_create::<galois_8::Field>(&registry, 1, 1).unwrap();
+        get_or_create::<galois_8::Field>(&registry, 3, 1).unwrap();
+        assert_eq!(2, registry.lock().codecs.len());
+
+        let b = get_or_create::<galois_8::Field>(&registry, 1, 1).unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+
+        // Evicted, so a new codec is created.
+        let c = get_or_create::<galois_8::Field>(&registry, 2, 1).unwrap();
+        assert_eq!(2, Arc::strong_count(&c));
+
+        registry.lock().set_capacity(Some(0));
+        assert!(registry.lock().codecs.is_empty());
+        assert_eq!(2, a.data_shard_count() + a.parity_shard_count());
+
+        registry.lock().set_capacity(None);
+        for i in 1..10 {
+            get_or_create::<galois_8::Field>(&registry, i, 1).unwrap();
+        }
+        assert_eq!(9, registry.lock().codecs.len());
+    }
+
+    #[test]
+    fn test_shared() {
+        let a = ReedSolomon::<galois_8::Field>::shared(7, 5).unwrap();
+        let b = ReedSolomon::<galois_8::Field>::shared(7, 5).unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+    }
+}
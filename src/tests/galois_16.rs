@@ -4,7 +4,8 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 use super::{fill_random, option_shards_into_shards, shards_into_option_shards};
-use crate::galois_16::ReedSolomon;
+use crate::galois_16::{self, ReedSolomon};
+use crate::{Error, ErrorKind};
 
 macro_rules! make_random_shards {
     ($per_shard:expr, $size:expr) => {{
@@ -33,6 +34,136 @@ fn correct_field_order_restriction() {
     assert!(ReedSolomon::new(1, ORDER - 1).is_ok());
 }
 
+#[test]
+fn test_encode_bytes_same_as_encode() {
+    let r = ReedSolomon::new(3, 2).unwrap();
+
+    let mut expect = make_random_shards!(50, 5);
+    r.encode(&mut expect).unwrap();
+
+    let mut shards: Vec<Vec<u8>> = expect
+        .iter()
+        .map(|s| s.iter().flat_map(|x| x.iter().cloned()).collect())
+        .collect();
+    for s in shards[3..].iter_mut() {
+        fill_random(s);
+    }
+
+    r.encode_bytes(&mut shards).unwrap();
+    assert!(r.verify_bytes(&shards).unwrap());
+
+    for (e, s) in expect.iter().zip(shards.iter()) {
+        assert_eq!(&e[..], galois_16::as_symbols(s).unwrap());
+    }
+}
+
+#[test]
+fn test_bytes_odd_shard_size() {
+    let r = ReedSolomon::new(3, 2).unwrap();
+
+    let mut shards = vec![vec![0u8; 7]; 5];
+    assert_eq!(ErrorKind::IncorrectShardSize, r.encode_bytes(&mut shards).unwrap_err().kind());
+    assert_eq!(ErrorKind::IncorrectShardSize, r.verify_bytes(&shards).unwrap_err().kind());
+
+    let mut shards: Vec<_> = shards.into_iter().map(Some).collect();
+    shards[0] = None;
+    assert_eq!(
+        ErrorKind::IncorrectShardSize,
+        r.reconstruct_bytes(&mut shards).unwrap_err().kind()
+    );
+
+    // A single odd shard is reported with its own index, and sizes in bytes.
+    let odd = Error::IncorrectShardSize {
+        index: 3,
+        expected: 8,
+        got: 7,
+    };
+    let mut shards = vec![vec![0u8; 8]; 5];
+    shards[3].pop();
+    assert_eq!(odd, r.encode_bytes(&mut shards).unwrap_err());
+    assert_eq!(odd, r.verify_bytes(&shards).unwrap_err());
+
+    let mut options: Vec<_> = shards.iter().cloned().map(Some).collect();
+    options[0] = None;
+    assert_eq!(odd, r.reconstruct_bytes(&mut options).unwrap_err());
+
+    let present: Vec<_> = shards.iter().map(Some).collect();
+    let mut outputs = [(0, vec![0u8; 8])];
+    assert_eq!(
+        odd,
+        r.reconstruct_sep_bytes(&present, &mut outputs).unwrap_err()
+    );
+
+    // Sizes differing by whole symbols are also reported in bytes.
+    shards[3].pop();
+    assert_eq!(
+        Error::IncorrectShardSize {
+            index: 3,
+            expected: 8,
+            got: 6
+        },
+        r.encode_bytes(&mut shards).unwrap_err()
+    );
+}
+
+#[test]
+fn test_reconstruct_bytes() {
+    let r = ReedSolomon::new(4, 3).unwrap();
+
+    let mut expect = vec![vec![0u8; 100]; 7];
+    for s in expect[..4].iter_mut() {
+        fill_random(s);
+    }
+    r.encode_bytes(&mut expect).unwrap();
+
+    let mut shards: Vec<_> = expect.iter().cloned().map(Some).collect();
+    shards[0] = None;
+    shards[2] = None;
+    shards[5] = None;
+
+    r.reconstruct_data_bytes(&mut shards).unwrap();
+    assert_eq!(shards[0].as_ref(), Some(&expect[0]));
+    assert_eq!(shards[2].as_ref(), Some(&expect[2]));
+    assert!(shards[5].is_none());
+
+    r.reconstruct_bytes(&mut shards).unwrap();
+    let shards: Vec<_> = shards.into_iter().map(Option::unwrap).collect();
+    assert_eq!(expect, shards);
+
+    let mut flagged: Vec<_> = expect.clone().into_iter().map(|s| (s, true)).collect();
+    flagged[1].1 = false;
+    flagged[6].1 = false;
+    fill_random(&mut flagged[1].0);
+    fill_random(&mut flagged[6].0);
+    r.reconstruct_bytes(&mut flagged).unwrap();
+    for (e, (s, _)) in expect.iter().zip(flagged.iter()) {
+        assert_eq!(e, s);
+    }
+
+    let mut present: Vec<_> = expect.iter().map(Some).collect();
+    present[3] = None;
+    present[4] = None;
+    let mut outputs = vec![(4, vec![0u8; 100]), (3, vec![0u8; 100])];
+    r.reconstruct_sep_bytes(&present, &mut outputs).unwrap();
+    assert_eq!(expect[4], outputs[0].1);
+    assert_eq!(expect[3], outputs[1].1);
+
+    let mut data_outputs = vec![(3, vec![0u8; 100])];
+    r.reconstruct_data_sep_bytes(&present, &mut data_outputs)
+        .unwrap();
+    assert_eq!(expect[3], data_outputs[0].1);
+
+    outputs[1].1.pop();
+    assert_eq!(
+        Error::IncorrectShardSize {
+            index: 3,
+            expected: 100,
+            got: 99
+        },
+        r.reconstruct_sep_bytes(&present, &mut outputs).unwrap_err()
+    );
+}
+
 quickcheck! {
     fn qc_encode_verify_reconstruct_verify(data: usize,
                                            parity: usize,
@@ -2,10 +2,11 @@
 
 extern crate alloc;
 
+use alloc::boxed::Box;
 use alloc::vec;
 use alloc::vec::Vec;
 
-use super::{galois_8, Error, SBSError};
+use super::{galois_8, Error, ErrorKind, SBSError, ShardSet};
 use rand::{self, thread_rng, Rng};
 
 mod galois_16;
@@ -96,25 +97,181 @@ fn option_shards_into_shards<T>(shards: Vec<Option<Vec<T>>>) -> Vec<Vec<T>> {
 
 #[test]
 fn test_no_data_shards() {
-    assert_eq!(Error::TooFewDataShards, ReedSolomon::new(0, 1).unwrap_err());
+    assert_eq!(ErrorKind::TooFewDataShards, ReedSolomon::new(0, 1).unwrap_err().kind());
 }
 
 #[test]
 fn test_no_parity_shards() {
     assert_eq!(
-        Error::TooFewParityShards,
-        ReedSolomon::new(1, 0).unwrap_err()
+        ErrorKind::TooFewParityShards,
+        ReedSolomon::new(1, 0).unwrap_err().kind()
     );
 }
 
 #[test]
 fn test_too_many_shards() {
     assert_eq!(
-        Error::TooManyShards,
-        ReedSolomon::new(129, 128).unwrap_err()
+        ErrorKind::TooManyShards,
+        ReedSolomon::new(129, 128).unwrap_err().kind()
     );
 }
 
+#[test]
+fn test_shard_count_overflow() {
+    assert_eq!(
+        Error::TooManyShards {
+            expected: 256,
+            got: usize::MAX
+        },
+        ReedSolomon::new(usize::MAX, 2).unwrap_err()
+    );
+}
+
+// GF(2^8) with an `nth` that repeats elements, so the Vandermonde matrix
+// can not be inverted.
+#[derive(Debug)]
+struct NonInjectiveField;
+
+impl crate::Field for NonInjectiveField {
+    const ORDER: usize = 256;
+    type Elem = u8;
+
+    fn add(a: u8, b: u8) -> u8 {
+        galois_8::add(a, b)
+    }
+
+    fn mul(a: u8, b: u8) -> u8 {
+        galois_8::mul(a, b)
+    }
+
+    fn div(a: u8, b: u8) -> u8 {
+        galois_8::div(a, b)
+    }
+
+    fn exp(a: u8, n: usize) -> u8 {
+        galois_8::exp(a, n)
+    }
+
+    fn zero() -> u8 {
+        0
+    }
+
+    fn one() -> u8 {
+        1
+    }
+
+    fn nth_internal(n: usize) -> u8 {
+        (n / 2) as u8
+    }
+}
+
+#[test]
+fn test_singular_matrix() {
+    assert_eq!(
+        ErrorKind::SingularMatrix,
+        crate::ReedSolomon::<NonInjectiveField>::new(3, 2).unwrap_err().kind()
+    );
+}
+
+#[test]
+fn test_error_context() {
+    let r = ReedSolomon::new(3, 2).unwrap();
+
+    let mut shards = make_random_shards!(10, 4);
+    assert_eq!(
+        Error::TooFewShards {
+            expected: 5,
+            got: 4
+        },
+        r.encode(&mut shards).unwrap_err()
+    );
+
+    let mut shards = make_random_shards!(10, 5);
+    shards[3] = vec![0; 9];
+    assert_eq!(
+        Error::IncorrectShardSize {
+            index: 3,
+            expected: 10,
+            got: 9
+        },
+        r.encode(&mut shards).unwrap_err()
+    );
+
+    // Parity shards are indexed after the data shards.
+    let mut shards = make_random_shards!(10, 5);
+    shards[4] = vec![0; 9];
+    let (data, parity) = shards.split_at_mut(3);
+    assert_eq!(
+        Error::IncorrectShardSize {
+            index: 4,
+            expected: 10,
+            got: 9
+        },
+        r.encode_sep(data, parity).unwrap_err()
+    );
+
+    let shards = make_random_shards!(10, 5);
+    assert_eq!(
+        Error::InvalidIndex { index: 3, count: 3 },
+        r.encode_single_sep(3, &shards[0], &mut shards[3..].to_vec())
+            .unwrap_err()
+    );
+
+    let mut shards = shards_to_option_shards(&make_random_shards!(10, 5));
+    shards[0] = None;
+    shards[1] = None;
+    shards[4] = None;
+    assert_eq!(
+        Error::TooFewShardsPresent {
+            present: 2,
+            needed: 3
+        },
+        r.reconstruct(&mut shards).unwrap_err()
+    );
+
+    // A missing shard with a buffer of the wrong size.
+    let mut shards: Vec<_> = make_random_shards!(10, 5)
+        .into_iter()
+        .map(|s| (s, true))
+        .collect();
+    shards[4] = (vec![0; 8], false);
+    assert_eq!(
+        Error::IncorrectShardSize {
+            index: 4,
+            expected: 10,
+            got: 8
+        },
+        r.reconstruct(&mut shards).unwrap_err()
+    );
+}
+
+#[test]
+fn test_try_nth() {
+    use crate::Field;
+
+    assert_eq!(Ok(255), galois_8::Field::try_nth(255));
+    assert_eq!(
+        Err(Error::InvalidFieldElement {
+            index: 256,
+            order: 256
+        }),
+        galois_8::Field::try_nth(256)
+    );
+}
+
+#[test]
+fn test_checked_div() {
+    use crate::Field;
+
+    assert_eq!(Some(galois_8::div(7, 3)), galois_8::Field::checked_div(7, 3));
+    assert_eq!(None, galois_8::Field::checked_div(7, 0));
+    assert_eq!(
+        Some(crate::galois_16::Field::div([1, 2], [3, 4])),
+        crate::galois_16::Field::checked_div([1, 2], [3, 4])
+    );
+    assert_eq!(None, crate::galois_16::Field::checked_div([1, 2], [0, 0]));
+}
+
 #[test]
 fn test_shard_count() {
     let mut rng = thread_rng();
@@ -138,6 +295,29 @@ fn test_reed_solomon_clone() {
     let r2 = r1.clone();
 
     assert_eq!(r1, r2);
+    assert_eq!(r1, ReedSolomon::new(10, 3).unwrap());
+    assert_ne!(r1, ReedSolomon::new(10, 4).unwrap());
+}
+
+#[test]
+fn test_reed_solomon_clone_shares_cache() {
+    let r1 = ReedSolomon::new(10, 3).unwrap();
+
+    let mut expect = make_random_shards!(100, 13);
+    r1.encode(&mut expect).unwrap();
+
+    let mut shards = shards_to_option_shards(&expect);
+    shards[0] = None;
+    r1.reconstruct(&mut shards).unwrap();
+    assert_eq!(1, r1.data_decode_matrix_cache_len());
+
+    let r2 = r1.clone();
+    assert_eq!(1, r2.data_decode_matrix_cache_len());
+
+    shards[1] = None;
+    r2.reconstruct(&mut shards).unwrap();
+    assert_eq!(2, r1.data_decode_matrix_cache_len());
+    assert_eq!(expect, option_shards_to_shards(&shards));
 }
 
 #[test]
@@ -152,15 +332,15 @@ fn test_encoding() {
     assert!(r.verify(&shards).unwrap());
 
     assert_eq!(
-        Error::TooFewShards,
-        r.encode(&mut shards[0..1]).unwrap_err()
+        ErrorKind::TooFewShards,
+        r.encode(&mut shards[0..1]).unwrap_err().kind()
     );
 
     let mut bad_shards = make_random_shards!(per_shard, 13);
     bad_shards[0] = vec![0 as u8];
     assert_eq!(
-        Error::IncorrectShardSize,
-        r.encode(&mut bad_shards).unwrap_err()
+        ErrorKind::IncorrectShardSize,
+        r.encode(&mut bad_shards).unwrap_err().kind()
     );
 }
 
@@ -240,8 +420,8 @@ fn test_reconstruct_shards() {
     shards[11] = None;
     shards[12] = None;
     assert_eq!(
-        r.reconstruct(&mut shards).unwrap_err(),
-        Error::TooFewShardsPresent
+        r.reconstruct(&mut shards).unwrap_err().kind(),
+        ErrorKind::TooFewShardsPresent
     );
 }
 
@@ -807,6 +987,477 @@ quickcheck! {
     }
 }
 
+#[test]
+fn test_reconstruct_with_flags() {
+    let r = ReedSolomon::new(8, 5).unwrap();
+
+    let mut expect = make_random_shards!(100, 13);
+    r.encode(&mut expect).unwrap();
+
+    let mut present = [true; 13];
+    let mut shards = expect.clone();
+    for &i in [0, 3, 8, 12].iter() {
+        present[i] = false;
+        fill_random(&mut shards[i]);
+    }
+
+    let mut data_shards = shards.clone();
+    r.reconstruct_data_with_flags(&mut data_shards, &present)
+        .unwrap();
+    assert_eq!(expect[..8], data_shards[..8]);
+    assert_eq!(shards[8..], data_shards[8..]);
+
+    r.reconstruct_with_flags(&mut shards, &present).unwrap();
+    assert_eq!(expect, shards);
+
+    assert_eq!(
+        Error::InvalidShardFlags {
+            expected: 13,
+            got: 12
+        },
+        r.reconstruct_with_flags(&mut shards, &present[..12])
+            .unwrap_err()
+    );
+
+    present[1] = false;
+    present[2] = false;
+    assert_eq!(
+        ErrorKind::TooFewShardsPresent,
+        r.reconstruct_with_flags(&mut shards, &present)
+            .unwrap_err()
+            .kind()
+    );
+}
+
+#[test]
+fn test_reconstruct_with_bitset() {
+    let r = ReedSolomon::new(50, 20).unwrap();
+
+    let mut expect = make_random_shards!(10, 70);
+    r.encode(&mut expect).unwrap();
+
+    let stale = [2, 40, 63, 64, 69];
+    let present: ShardSet = (0..70).filter(|i| !stale.contains(i)).collect();
+    assert_eq!(&[!(1 << 2 | 1 << 40 | 1 << 63), !(1 | 1 << 5) & 0x3F], present.words());
+    let mut shards = expect.clone();
+    for &i in stale.iter() {
+        fill_random(&mut shards[i]);
+    }
+
+    let mut data_shards = shards.clone();
+    r.reconstruct_data_with_bitset(&mut data_shards, &present)
+        .unwrap();
+    assert_eq!(expect[..50], data_shards[..50]);
+    assert_eq!(shards[50..], data_shards[50..]);
+
+    r.reconstruct_with_bitset(&mut shards, &present).unwrap();
+    assert_eq!(expect, shards);
+
+    let mut present = ShardSet::full(70);
+    present.insert(74);
+    assert_eq!(
+        Error::InvalidShardFlags {
+            expected: 70,
+            got: 75
+        },
+        r.reconstruct_with_bitset(&mut shards, &present)
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_reconstruct_sep() {
+    let r = ReedSolomon::new(8, 5).unwrap();
+
+    let mut expect = make_random_shards!(100, 13);
+    r.encode(&mut expect).unwrap();
+
+    let missing = [1, 4, 7, 9, 12];
+    let present: Vec<Option<&[u8]>> = expect
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            if missing.contains(&i) {
+                None
+            } else {
+                Some(&s[..])
+            }
+        })
+        .collect();
+
+    // Every shard, missing or not, in no particular order.
+    let mut outputs: Vec<(usize, Vec<u8>)> = [12, 0, 4, 9, 1, 5, 7]
+        .iter()
+        .map(|&i| (i, vec![0; 100]))
+        .collect();
+    r.reconstruct_sep(&present, &mut outputs).unwrap();
+    for (i, output) in outputs.iter() {
+        assert_eq!(&expect[*i], output);
+    }
+
+    // Only data shards, from parity shards.
+    let present: Vec<Option<&[u8]>> = expect
+        .iter()
+        .enumerate()
+        .map(|(i, s)| if i < 5 { None } else { Some(&s[..]) })
+        .collect();
+    let mut outputs: Vec<_> = (0..5).map(|i| (i, vec![0; 100])).collect();
+    r.reconstruct_sep(&present, &mut outputs).unwrap();
+    for (i, output) in outputs.iter() {
+        assert_eq!(&expect[*i], output);
+    }
+
+    // Nothing requested.
+    r.reconstruct_sep(&present, &mut Vec::<(usize, Vec<u8>)>::new())
+        .unwrap();
+}
+
+#[test]
+fn test_reconstruct_data_sep() {
+    let r = ReedSolomon::new(8, 5).unwrap();
+
+    let mut expect = make_random_shards!(100, 13);
+    r.encode(&mut expect).unwrap();
+
+    let present: Vec<Option<&[u8]>> = expect
+        .iter()
+        .enumerate()
+        .map(|(i, s)| if i % 3 == 0 { None } else { Some(&s[..]) })
+        .collect();
+
+    let mut outputs: Vec<_> = (0..8).map(|i| (i, vec![0; 100])).collect();
+    r.reconstruct_data_sep(&present, &mut outputs).unwrap();
+    for (i, output) in outputs.iter() {
+        assert_eq!(&expect[*i], output);
+    }
+
+    let mut outputs = vec![(3, vec![0; 100]), (9, vec![0; 100])];
+    assert_eq!(
+        Error::InvalidIndex { index: 9, count: 8 },
+        r.reconstruct_data_sep(&present, &mut outputs).unwrap_err()
+    );
+    assert_eq!(vec![0; 100], outputs[0].1);
+}
+
+#[test]
+fn test_reconstruct_sep_error_handling() {
+    let r = ReedSolomon::new(3, 2).unwrap();
+
+    let mut expect = make_random_shards!(10, 5);
+    r.encode(&mut expect).unwrap();
+
+    let mut present: Vec<Option<&[u8]>> = expect.iter().map(|s| Some(&s[..])).collect();
+    present[0] = None;
+    present[3] = None;
+
+    let mut outputs = vec![(0, vec![0u8; 10]), (3, vec![0u8; 10])];
+    assert_eq!(
+        Error::TooFewShards {
+            expected: 5,
+            got: 4
+        },
+        r.reconstruct_sep(&present[..4], &mut outputs).unwrap_err()
+    );
+
+    let mut outputs = vec![(0, vec![0u8; 10]), (5, vec![0u8; 10])];
+    assert_eq!(
+        Error::InvalidIndex { index: 5, count: 5 },
+        r.reconstruct_sep(&present, &mut outputs).unwrap_err()
+    );
+
+    let mut outputs = vec![(0, vec![0u8; 10]), (3, vec![0u8; 9])];
+    assert_eq!(
+        Error::IncorrectShardSize {
+            index: 3,
+            expected: 10,
+            got: 9
+        },
+        r.reconstruct_sep(&present, &mut outputs).unwrap_err()
+    );
+    // Nothing was written.
+    assert_eq!(vec![0u8; 10], outputs[0].1);
+
+    present[4] = Some(&expect[4][..9]);
+    let mut outputs = vec![(0, vec![0u8; 10])];
+    assert_eq!(
+        Error::IncorrectShardSize {
+            index: 4,
+            expected: 10,
+            got: 9
+        },
+        r.reconstruct_sep(&present, &mut outputs).unwrap_err()
+    );
+
+    present[4] = None;
+    assert_eq!(
+        Error::TooFewShardsPresent {
+            present: 2,
+            needed: 3
+        },
+        r.reconstruct_sep(&present, &mut outputs).unwrap_err()
+    );
+}
+
+#[test]
+fn test_reconstruct_shard_impls() {
+    let r = ReedSolomon::new(4, 3).unwrap();
+
+    let mut expect = make_random_shards!(16, 7);
+    r.encode(&mut expect).unwrap();
+
+    let mut shards: Vec<Option<Box<[u8]>>> = expect
+        .iter()
+        .map(|s| Some(s.clone().into_boxed_slice()))
+        .collect();
+    shards[1] = None;
+    shards[5] = None;
+    r.reconstruct(&mut shards).unwrap();
+    for (e, s) in expect.iter().zip(shards.iter()) {
+        assert_eq!(&e[..], &s.as_ref().unwrap()[..]);
+    }
+
+    let mut shards: Vec<([u8; 16], bool)> = expect
+        .iter()
+        .map(|s| {
+            let mut a = [0; 16];
+            a.copy_from_slice(s);
+            (a, true)
+        })
+        .collect();
+    shards[0].1 = false;
+    shards[6].1 = false;
+    r.reconstruct(&mut shards).unwrap();
+    for (e, s) in expect.iter().zip(shards.iter()) {
+        assert_eq!(&e[..], &s.0[..]);
+    }
+
+    // Pre-allocated buffers of missing shards are overwritten.
+    let mut buffers = expect.clone();
+    fill_random(&mut buffers[2]);
+    fill_random(&mut buffers[4]);
+    let mut shards: Vec<(&mut [u8], bool)> = buffers
+        .iter_mut()
+        .enumerate()
+        .map(|(i, s)| (&mut s[..], i != 2 && i != 4))
+        .collect();
+    r.reconstruct(&mut shards).unwrap();
+    assert_eq!(expect, buffers);
+
+    let mut shards: Vec<Option<Buffer>> = expect.iter().map(|s| Some(Buffer(s.clone()))).collect();
+    shards[3] = None;
+    r.reconstruct(&mut shards).unwrap();
+    for (e, s) in expect.iter().zip(shards.iter()) {
+        assert_eq!(e, &s.as_ref().unwrap().0);
+    }
+}
+
+// A buffer type implemented outside of the crate.
+struct Buffer(Vec<u8>);
+
+impl AsRef<[u8]> for Buffer {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Buffer {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl core::iter::FromIterator<u8> for Buffer {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        Buffer(iter.into_iter().collect())
+    }
+}
+
+#[test]
+fn test_reconstruct_with_unavailable_shards() {
+    let r = ReedSolomon::new(4, 3).unwrap();
+
+    let mut expect = make_random_shards!(16, 7);
+    r.encode(&mut expect).unwrap();
+
+    let shards = |missing: &[usize], unwanted: usize| -> Vec<ReconstructInto> {
+        expect
+            .iter()
+            .enumerate()
+            .map(|(i, s)| ReconstructInto {
+                buffer: if missing.contains(&i) {
+                    vec![0; 16]
+                } else {
+                    s.clone()
+                },
+                present: !missing.contains(&i),
+                wanted: i != unwanted,
+            })
+            .collect()
+    };
+
+    // Missing parity shards without a buffer are left alone.
+    let mut unwanted_parity = shards(&[1, 6], 6);
+    r.reconstruct(&mut unwanted_parity).unwrap();
+    assert_eq!(expect[1], unwanted_parity[1].buffer);
+    assert_eq!(vec![0; 16], unwanted_parity[6].buffer);
+
+    // Missing data shards cannot be left unrecovered.
+    let mut unwanted_data = shards(&[1, 5], 1);
+    assert_eq!(
+        Error::UnavailableShard { index: 1 },
+        r.reconstruct(&mut unwanted_data).unwrap_err()
+    );
+    let mut unwanted_data = shards(&[1, 5], 1);
+    assert_eq!(
+        Error::UnavailableShard { index: 1 },
+        r.reconstruct_data(&mut unwanted_data).unwrap_err()
+    );
+}
+
+// A shard type implemented outside of the crate, which only reconstructs
+// the missing shards that are wanted.
+struct ReconstructInto {
+    buffer: Vec<u8>,
+    present: bool,
+    wanted: bool,
+}
+
+impl crate::ReconstructShard<galois_8::Field> for ReconstructInto {
+    fn len(&self) -> Option<usize> {
+        if self.present {
+            Some(self.buffer.len())
+        } else {
+            None
+        }
+    }
+
+    fn get(&mut self) -> Option<&mut [u8]> {
+        if self.present {
+            Some(&mut self.buffer)
+        } else {
+            None
+        }
+    }
+
+    fn get_or_initialize(&mut self, len: usize) -> Result<crate::ShardState<'_, u8>, Error> {
+        Ok(if self.present {
+            crate::ShardState::Present(&mut self.buffer)
+        } else if self.wanted {
+            self.buffer.resize(len, 0);
+            crate::ShardState::Missing(&mut self.buffer)
+        } else {
+            crate::ShardState::Unavailable
+        })
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_reconstruct_bytes_mut() {
+    use bytes::BytesMut;
+
+    let r = ReedSolomon::new(4, 3).unwrap();
+
+    let mut expect = make_random_shards!(16, 7);
+    r.encode(&mut expect).unwrap();
+
+    let mut shards: Vec<Option<BytesMut>> =
+        expect.iter().map(|s| Some(BytesMut::from(&s[..]))).collect();
+    shards[2] = None;
+    shards[4] = None;
+    r.reconstruct(&mut shards).unwrap();
+    for (e, s) in expect.iter().zip(shards.iter()) {
+        assert_eq!(&e[..], &s.as_ref().unwrap()[..]);
+    }
+}
+
+#[test]
+fn test_reconstruct_from_indexed() {
+    let r = ReedSolomon::new(5, 3).unwrap();
+
+    let mut expect = make_random_shards!(20, 8);
+    r.encode(&mut expect).unwrap();
+
+    // Arrival order, the last shards are never looked at.
+    let order = [6, 2, 7, 0, 4, 100, 2];
+    let received = order.iter().map(|&i| (i, expect.get(i).map_or(&[][..], |s| &s[..])));
+
+    let data = r.reconstruct_from_indexed(received.clone()).unwrap();
+    assert_eq!(expect[..5], data[..]);
+
+    let mut data = vec![vec![0u8; 20]; 5];
+    r.reconstruct_from_indexed_into(received, &mut data)
+        .unwrap();
+    assert_eq!(expect[..5], data[..]);
+
+    assert_eq!(
+        Error::DuplicateShard { index: 2 },
+        r.reconstruct_from_indexed(vec![(1, &expect[1]), (2, &expect[2]), (2, &expect[2])])
+            .unwrap_err()
+    );
+    assert_eq!(
+        Error::InvalidIndex { index: 8, count: 8 },
+        r.reconstruct_from_indexed(vec![(1, &expect[1]), (8, &expect[2])])
+            .unwrap_err()
+    );
+    assert_eq!(
+        Error::TooFewShardsPresent {
+            present: 4,
+            needed: 5
+        },
+        r.reconstruct_from_indexed(expect.iter().enumerate().skip(4))
+            .unwrap_err()
+    );
+    assert_eq!(
+        Error::IncorrectShardSize {
+            index: 3,
+            expected: 20,
+            got: 19
+        },
+        r.reconstruct_from_indexed(
+            expect
+                .iter()
+                .enumerate()
+                .map(|(i, s)| (i, if i == 3 { &s[1..] } else { &s[..] }))
+        )
+        .unwrap_err()
+    );
+
+    let mut data = vec![vec![0u8; 20]; 4];
+    assert_eq!(
+        ErrorKind::TooFewDataShards,
+        r.reconstruct_from_indexed_into(expect.iter().enumerate(), &mut data)
+            .unwrap_err()
+            .kind()
+    );
+}
+
+#[test]
+fn test_reconstruct_from_indexed_stops_at_data_shard_count() {
+    let r = ReedSolomon::new(5, 3).unwrap();
+
+    let mut expect = make_random_shards!(20, 8);
+    r.encode(&mut expect).unwrap();
+
+    let received = || {
+        [7, 1, 3, 5, 0]
+            .iter()
+            .cloned()
+            .chain(core::iter::from_fn(|| -> Option<usize> {
+                panic!("polled past the data shard count")
+            }))
+            .map(|i| (i, &expect[i][..]))
+    };
+
+    let data = r.reconstruct_from_indexed(received()).unwrap();
+    assert_eq!(expect[..5], data[..]);
+
+    let mut data = vec![vec![0u8; 20]; 5];
+    r.reconstruct_from_indexed_into(received(), &mut data)
+        .unwrap();
+    assert_eq!(expect[..5], data[..]);
+}
+
 #[test]
 fn test_reconstruct_error_handling() {
     let r = ReedSolomon::new(2, 2).unwrap();
@@ -838,8 +1489,8 @@ fn test_reconstruct_error_handling() {
             .collect();
 
         assert_eq!(
-            Error::TooFewShardsPresent,
-            r.reconstruct(&mut shard_refs[..]).unwrap_err()
+            ErrorKind::TooFewShardsPresent,
+            r.reconstruct(&mut shard_refs[..]).unwrap_err().kind()
         );
 
         shard_refs[3].1 = true;
@@ -898,7 +1549,7 @@ fn test_verify_too_few_shards() {
 
     let shards = make_random_shards!(10, 4);
 
-    assert_eq!(Error::TooFewShards, r.verify(&shards).unwrap_err());
+    assert_eq!(ErrorKind::TooFewShards, r.verify(&shards).unwrap_err().kind());
 }
 
 #[test]
@@ -912,8 +1563,8 @@ fn test_verify_shards_with_buffer_incorrect_buffer_sizes() {
         let mut buffer = vec![vec![0; 100]; 1];
 
         assert_eq!(
-            Error::TooFewBufferShards,
-            r.verify_with_buffer(&shards, &mut buffer).unwrap_err()
+            ErrorKind::TooFewBufferShards,
+            r.verify_with_buffer(&shards, &mut buffer).unwrap_err().kind()
         );
     }
     {
@@ -923,8 +1574,8 @@ fn test_verify_shards_with_buffer_incorrect_buffer_sizes() {
         let mut buffer = vec![vec![0; 100]; 3];
 
         assert_eq!(
-            Error::TooManyBufferShards,
-            r.verify_with_buffer(&shards, &mut buffer).unwrap_err()
+            ErrorKind::TooManyBufferShards,
+            r.verify_with_buffer(&shards, &mut buffer).unwrap_err().kind()
         );
     }
     {
@@ -945,8 +1596,8 @@ fn test_verify_shards_with_buffer_incorrect_buffer_sizes() {
         buffer[0] = vec![];
 
         assert_eq!(
-            Error::EmptyShard,
-            r.verify_with_buffer(&shards, &mut buffer).unwrap_err()
+            ErrorKind::EmptyShard,
+            r.verify_with_buffer(&shards, &mut buffer).unwrap_err().kind()
         );
     }
     {
@@ -957,8 +1608,8 @@ fn test_verify_shards_with_buffer_incorrect_buffer_sizes() {
         buffer[1] = vec![0; 99];
 
         assert_eq!(
-            Error::IncorrectShardSize,
-            r.verify_with_buffer(&shards, &mut buffer).unwrap_err()
+            ErrorKind::IncorrectShardSize,
+            r.verify_with_buffer(&shards, &mut buffer).unwrap_err().kind()
         );
     }
 }
@@ -1062,27 +1713,27 @@ fn test_slices_or_shards_count_check() {
     {
         let mut shards = make_random_shards!(10, 4);
 
-        assert_eq!(Error::TooFewShards, r.encode(&mut shards).unwrap_err());
-        assert_eq!(Error::TooFewShards, r.verify(&shards).unwrap_err());
+        assert_eq!(ErrorKind::TooFewShards, r.encode(&mut shards).unwrap_err().kind());
+        assert_eq!(ErrorKind::TooFewShards, r.verify(&shards).unwrap_err().kind());
 
         let mut option_shards = shards_to_option_shards(&shards);
 
         assert_eq!(
-            Error::TooFewShards,
-            r.reconstruct(&mut option_shards).unwrap_err()
+            ErrorKind::TooFewShards,
+            r.reconstruct(&mut option_shards).unwrap_err().kind()
         );
     }
     {
         let mut shards = make_random_shards!(10, 6);
 
-        assert_eq!(Error::TooManyShards, r.encode(&mut shards).unwrap_err());
-        assert_eq!(Error::TooManyShards, r.verify(&shards).unwrap_err());
+        assert_eq!(ErrorKind::TooManyShards, r.encode(&mut shards).unwrap_err().kind());
+        assert_eq!(ErrorKind::TooManyShards, r.verify(&shards).unwrap_err().kind());
 
         let mut option_shards = shards_to_option_shards(&shards);
 
         assert_eq!(
-            Error::TooManyShards,
-            r.reconstruct(&mut option_shards).unwrap_err()
+            ErrorKind::TooManyShards,
+            r.reconstruct(&mut option_shards).unwrap_err().kind()
         );
     }
 }
@@ -1095,69 +1746,69 @@ fn test_check_slices_or_shards_size() {
         let mut shards = shards!([0, 0, 0], [0, 1], [1, 2, 3], [0, 0, 0]);
 
         assert_eq!(
-            Error::IncorrectShardSize,
-            r.encode(&mut shards).unwrap_err()
+            ErrorKind::IncorrectShardSize,
+            r.encode(&mut shards).unwrap_err().kind()
         );
-        assert_eq!(Error::IncorrectShardSize, r.verify(&shards).unwrap_err());
+        assert_eq!(ErrorKind::IncorrectShardSize, r.verify(&shards).unwrap_err().kind());
 
         let mut option_shards = shards_to_option_shards(&shards);
 
         assert_eq!(
-            Error::IncorrectShardSize,
-            r.reconstruct(&mut option_shards).unwrap_err()
+            ErrorKind::IncorrectShardSize,
+            r.reconstruct(&mut option_shards).unwrap_err().kind()
         );
     }
     {
         let mut shards = shards!([0, 1], [0, 1], [1, 2, 3], [0, 0, 0]);
 
         assert_eq!(
-            Error::IncorrectShardSize,
-            r.encode(&mut shards).unwrap_err()
+            ErrorKind::IncorrectShardSize,
+            r.encode(&mut shards).unwrap_err().kind()
         );
-        assert_eq!(Error::IncorrectShardSize, r.verify(&shards).unwrap_err());
+        assert_eq!(ErrorKind::IncorrectShardSize, r.verify(&shards).unwrap_err().kind());
 
         let mut option_shards = shards_to_option_shards(&shards);
 
         assert_eq!(
-            Error::IncorrectShardSize,
-            r.reconstruct(&mut option_shards).unwrap_err()
+            ErrorKind::IncorrectShardSize,
+            r.reconstruct(&mut option_shards).unwrap_err().kind()
         );
     }
     {
         let mut shards = shards!([0, 1], [0, 1, 4], [1, 2, 3], [0, 0, 0]);
 
         assert_eq!(
-            Error::IncorrectShardSize,
-            r.encode(&mut shards).unwrap_err()
+            ErrorKind::IncorrectShardSize,
+            r.encode(&mut shards).unwrap_err().kind()
         );
-        assert_eq!(Error::IncorrectShardSize, r.verify(&shards).unwrap_err());
+        assert_eq!(ErrorKind::IncorrectShardSize, r.verify(&shards).unwrap_err().kind());
 
         let mut option_shards = shards_to_option_shards(&shards);
 
         assert_eq!(
-            Error::IncorrectShardSize,
-            r.reconstruct(&mut option_shards).unwrap_err()
+            ErrorKind::IncorrectShardSize,
+            r.reconstruct(&mut option_shards).unwrap_err().kind()
         );
     }
     {
         let mut shards = shards!([], [0, 1, 3], [1, 2, 3], [0, 0, 0]);
 
-        assert_eq!(Error::EmptyShard, r.encode(&mut shards).unwrap_err());
-        assert_eq!(Error::EmptyShard, r.verify(&shards).unwrap_err());
+        assert_eq!(ErrorKind::EmptyShard, r.encode(&mut shards).unwrap_err().kind());
+        assert_eq!(ErrorKind::EmptyShard, r.verify(&shards).unwrap_err().kind());
 
         let mut option_shards = shards_to_option_shards(&shards);
 
         assert_eq!(
-            Error::EmptyShard,
-            r.reconstruct(&mut option_shards).unwrap_err()
+            ErrorKind::EmptyShard,
+            r.reconstruct(&mut option_shards).unwrap_err().kind()
         );
     }
     {
         let mut option_shards: Vec<Option<Vec<u8>>> = vec![None, None, None, None];
 
         assert_eq!(
-            Error::TooFewShardsPresent,
-            r.reconstruct(&mut option_shards).unwrap_err()
+            ErrorKind::TooFewShardsPresent,
+            r.reconstruct(&mut option_shards).unwrap_err().kind()
         );
     }
 }
@@ -1607,7 +2258,10 @@ fn shardbyshard_encode_error_handling() {
             sbs.encode(&mut slice_refs).unwrap();
         }
 
-        assert_eq!(SBSError::LeftoverShards, sbs.reset().unwrap_err());
+        assert_eq!(
+            SBSError::LeftoverShards { remaining: 9 },
+            sbs.reset().unwrap_err()
+        );
 
         sbs.reset_force();
 
@@ -1625,14 +2279,14 @@ fn shardbyshard_encode_error_handling() {
             assert_eq!(0, sbs.cur_input_index());
 
             assert_eq!(
-                SBSError::RSError(Error::EmptyShard),
+                SBSError::RSError(Error::EmptyShard { index: 0 }),
                 sbs.encode(&mut slice_refs).unwrap_err()
             );
 
             assert_eq!(0, sbs.cur_input_index());
 
             assert_eq!(
-                SBSError::RSError(Error::EmptyShard),
+                SBSError::RSError(Error::EmptyShard { index: 0 }),
                 sbs.encode(&mut slice_refs).unwrap_err()
             );
 
@@ -1659,14 +2313,22 @@ fn shardbyshard_encode_error_handling() {
             assert_eq!(0, sbs.cur_input_index());
 
             assert_eq!(
-                SBSError::RSError(Error::IncorrectShardSize),
+                SBSError::RSError(Error::IncorrectShardSize {
+                    index: 1,
+                    expected: 100,
+                    got: 99
+                }),
                 sbs.encode(&mut slice_refs).unwrap_err()
             );
 
             assert_eq!(0, sbs.cur_input_index());
 
             assert_eq!(
-                SBSError::RSError(Error::IncorrectShardSize),
+                SBSError::RSError(Error::IncorrectShardSize {
+                    index: 1,
+                    expected: 100,
+                    got: 99
+                }),
                 sbs.encode(&mut slice_refs).unwrap_err()
             );
 
@@ -1709,7 +2371,10 @@ fn shardbyshard_encode_shard_error_handling() {
             sbs.encode(&mut shards).unwrap();
         }
 
-        assert_eq!(SBSError::LeftoverShards, sbs.reset().unwrap_err());
+        assert_eq!(
+            SBSError::LeftoverShards { remaining: 9 },
+            sbs.reset().unwrap_err()
+        );
 
         sbs.reset_force();
 
@@ -1725,14 +2390,14 @@ fn shardbyshard_encode_shard_error_handling() {
             assert_eq!(0, sbs.cur_input_index());
 
             assert_eq!(
-                SBSError::RSError(Error::EmptyShard),
+                SBSError::RSError(Error::EmptyShard { index: 0 }),
                 sbs.encode(&mut shards).unwrap_err()
             );
 
             assert_eq!(0, sbs.cur_input_index());
 
             assert_eq!(
-                SBSError::RSError(Error::EmptyShard),
+                SBSError::RSError(Error::EmptyShard { index: 0 }),
                 sbs.encode(&mut shards).unwrap_err()
             );
 
@@ -1755,14 +2420,22 @@ fn shardbyshard_encode_shard_error_handling() {
             assert_eq!(0, sbs.cur_input_index());
 
             assert_eq!(
-                SBSError::RSError(Error::IncorrectShardSize),
+                SBSError::RSError(Error::IncorrectShardSize {
+                    index: 1,
+                    expected: 100,
+                    got: 99
+                }),
                 sbs.encode(&mut shards).unwrap_err()
             );
 
             assert_eq!(0, sbs.cur_input_index());
 
             assert_eq!(
-                SBSError::RSError(Error::IncorrectShardSize),
+                SBSError::RSError(Error::IncorrectShardSize {
+                    index: 1,
+                    expected: 100,
+                    got: 99
+                }),
                 sbs.encode(&mut shards).unwrap_err()
             );
 
@@ -1808,7 +2481,10 @@ fn shardbyshard_encode_sep_error_handling() {
             sbs.encode_sep(data, parity).unwrap();
         }
 
-        assert_eq!(SBSError::LeftoverShards, sbs.reset().unwrap_err());
+        assert_eq!(
+            SBSError::LeftoverShards { remaining: 9 },
+            sbs.reset().unwrap_err()
+        );
 
         sbs.reset_force();
 
@@ -1850,7 +2526,10 @@ fn shardbyshard_encode_sep_error_handling() {
             }
         }
 
-        assert_eq!(SBSError::LeftoverShards, sbs.reset().unwrap_err());
+        assert_eq!(
+            SBSError::LeftoverShards { remaining: 9 },
+            sbs.reset().unwrap_err()
+        );
 
         sbs.reset_force();
 
@@ -1874,14 +2553,14 @@ fn shardbyshard_encode_sep_error_handling() {
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::EmptyShard),
+                    SBSError::RSError(Error::EmptyShard { index: 0 }),
                     sbs.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
                 );
 
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::EmptyShard),
+                    SBSError::RSError(Error::EmptyShard { index: 0 }),
                     sbs.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
                 );
 
@@ -1913,14 +2592,14 @@ fn shardbyshard_encode_sep_error_handling() {
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::EmptyShard),
+                    SBSError::RSError(Error::EmptyShard { index: 10 }),
                     sbs.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
                 );
 
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::EmptyShard),
+                    SBSError::RSError(Error::EmptyShard { index: 10 }),
                     sbs.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
                 );
 
@@ -1955,14 +2634,22 @@ fn shardbyshard_encode_sep_error_handling() {
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::IncorrectShardSize),
+                    SBSError::RSError(Error::IncorrectShardSize {
+                    index: 1,
+                    expected: 100,
+                    got: 99
+                }),
                     sbs.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
                 );
 
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::IncorrectShardSize),
+                    SBSError::RSError(Error::IncorrectShardSize {
+                    index: 1,
+                    expected: 100,
+                    got: 99
+                }),
                     sbs.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
                 );
 
@@ -1994,14 +2681,22 @@ fn shardbyshard_encode_sep_error_handling() {
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::IncorrectShardSize),
+                    SBSError::RSError(Error::IncorrectShardSize {
+                    index: 11,
+                    expected: 100,
+                    got: 99
+                }),
                     sbs.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
                 );
 
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::IncorrectShardSize),
+                    SBSError::RSError(Error::IncorrectShardSize {
+                    index: 11,
+                    expected: 100,
+                    got: 99
+                }),
                     sbs.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
                 );
 
@@ -2053,7 +2748,10 @@ fn shardbyshard_encode_shard_sep_error_handling() {
             sbs.encode_sep(data, parity).unwrap();
         }
 
-        assert_eq!(SBSError::LeftoverShards, sbs.reset().unwrap_err());
+        assert_eq!(
+            SBSError::LeftoverShards { remaining: 9 },
+            sbs.reset().unwrap_err()
+        );
 
         sbs.reset_force();
 
@@ -2074,14 +2772,14 @@ fn shardbyshard_encode_shard_sep_error_handling() {
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::EmptyShard),
+                    SBSError::RSError(Error::EmptyShard { index: 0 }),
                     sbs.encode_sep(data, parity).unwrap_err()
                 );
 
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::EmptyShard),
+                    SBSError::RSError(Error::EmptyShard { index: 0 }),
                     sbs.encode_sep(data, parity).unwrap_err()
                 );
 
@@ -2107,14 +2805,14 @@ fn shardbyshard_encode_shard_sep_error_handling() {
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::EmptyShard),
+                    SBSError::RSError(Error::EmptyShard { index: 10 }),
                     sbs.encode_sep(data, parity).unwrap_err()
                 );
 
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::EmptyShard),
+                    SBSError::RSError(Error::EmptyShard { index: 10 }),
                     sbs.encode_sep(data, parity).unwrap_err()
                 );
 
@@ -2143,14 +2841,22 @@ fn shardbyshard_encode_shard_sep_error_handling() {
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::IncorrectShardSize),
+                    SBSError::RSError(Error::IncorrectShardSize {
+                    index: 1,
+                    expected: 100,
+                    got: 99
+                }),
                     sbs.encode_sep(data, parity).unwrap_err()
                 );
 
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::IncorrectShardSize),
+                    SBSError::RSError(Error::IncorrectShardSize {
+                    index: 1,
+                    expected: 100,
+                    got: 99
+                }),
                     sbs.encode_sep(data, parity).unwrap_err()
                 );
 
@@ -2176,14 +2882,22 @@ fn shardbyshard_encode_shard_sep_error_handling() {
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::IncorrectShardSize),
+                    SBSError::RSError(Error::IncorrectShardSize {
+                    index: 11,
+                    expected: 100,
+                    got: 99
+                }),
                     sbs.encode_sep(data, parity).unwrap_err()
                 );
 
                 assert_eq!(0, sbs.cur_input_index());
 
                 assert_eq!(
-                    SBSError::RSError(Error::IncorrectShardSize),
+                    SBSError::RSError(Error::IncorrectShardSize {
+                    index: 11,
+                    expected: 100,
+                    got: 99
+                }),
                     sbs.encode_sep(data, parity).unwrap_err()
                 );
 
@@ -2315,24 +3029,24 @@ fn test_encode_single_sep_error_handling() {
             }
 
             assert_eq!(
-                Error::InvalidIndex,
-                r.encode_single_sep(10, &data[0], parity).unwrap_err()
+                ErrorKind::InvalidIndex,
+                r.encode_single_sep(10, &data[0], parity).unwrap_err().kind()
             );
             assert_eq!(
-                Error::InvalidIndex,
-                r.encode_single_sep(11, &data[0], parity).unwrap_err()
+                ErrorKind::InvalidIndex,
+                r.encode_single_sep(11, &data[0], parity).unwrap_err().kind()
             );
             assert_eq!(
-                Error::InvalidIndex,
-                r.encode_single_sep(12, &data[0], parity).unwrap_err()
+                ErrorKind::InvalidIndex,
+                r.encode_single_sep(12, &data[0], parity).unwrap_err().kind()
             );
             assert_eq!(
-                Error::InvalidIndex,
-                r.encode_single_sep(13, &data[0], parity).unwrap_err()
+                ErrorKind::InvalidIndex,
+                r.encode_single_sep(13, &data[0], parity).unwrap_err().kind()
             );
             assert_eq!(
-                Error::InvalidIndex,
-                r.encode_single_sep(14, &data[0], parity).unwrap_err()
+                ErrorKind::InvalidIndex,
+                r.encode_single_sep(14, &data[0], parity).unwrap_err().kind()
             );
         }
 
@@ -2340,16 +3054,16 @@ fn test_encode_single_sep_error_handling() {
             let (data, parity) = shards.split_at_mut(11);
 
             assert_eq!(
-                Error::TooFewParityShards,
-                r.encode_single_sep(0, &data[0], parity).unwrap_err()
+                ErrorKind::TooFewParityShards,
+                r.encode_single_sep(0, &data[0], parity).unwrap_err().kind()
             );
         }
         {
             let (data, parity) = shards.split_at_mut(9);
 
             assert_eq!(
-                Error::TooManyParityShards,
-                r.encode_single_sep(0, &data[0], parity).unwrap_err()
+                ErrorKind::TooManyParityShards,
+                r.encode_single_sep(0, &data[0], parity).unwrap_err().kind()
             );
         }
     }
@@ -2371,29 +3085,29 @@ fn test_encode_single_sep_error_handling() {
             }
 
             assert_eq!(
-                Error::InvalidIndex,
+                ErrorKind::InvalidIndex,
                 r.encode_single_sep(10, &data_refs[0], &mut parity_refs)
-                    .unwrap_err()
+                    .unwrap_err().kind()
             );
             assert_eq!(
-                Error::InvalidIndex,
+                ErrorKind::InvalidIndex,
                 r.encode_single_sep(11, &data_refs[0], &mut parity_refs)
-                    .unwrap_err()
+                    .unwrap_err().kind()
             );
             assert_eq!(
-                Error::InvalidIndex,
+                ErrorKind::InvalidIndex,
                 r.encode_single_sep(12, &data_refs[0], &mut parity_refs)
-                    .unwrap_err()
+                    .unwrap_err().kind()
             );
             assert_eq!(
-                Error::InvalidIndex,
+                ErrorKind::InvalidIndex,
                 r.encode_single_sep(13, &data_refs[0], &mut parity_refs)
-                    .unwrap_err()
+                    .unwrap_err().kind()
             );
             assert_eq!(
-                Error::InvalidIndex,
+                ErrorKind::InvalidIndex,
                 r.encode_single_sep(14, &data_refs[0], &mut parity_refs)
-                    .unwrap_err()
+                    .unwrap_err().kind()
             );
         }
         {
@@ -2403,9 +3117,9 @@ fn test_encode_single_sep_error_handling() {
             let mut parity_refs = convert_2D_slices!(parity=>to_mut_vec &mut [u8]);
 
             assert_eq!(
-                Error::TooFewParityShards,
+                ErrorKind::TooFewParityShards,
                 r.encode_single_sep(0, &data_refs[0], &mut parity_refs)
-                    .unwrap_err()
+                    .unwrap_err().kind()
             );
         }
         {
@@ -2415,9 +3129,9 @@ fn test_encode_single_sep_error_handling() {
             let mut parity_refs = convert_2D_slices!(parity=>to_mut_vec &mut [u8]);
 
             assert_eq!(
-                Error::TooManyParityShards,
+                ErrorKind::TooManyParityShards,
                 r.encode_single_sep(0, &data_refs[0], &mut parity_refs)
-                    .unwrap_err()
+                    .unwrap_err().kind()
             );
         }
     }
@@ -2439,8 +3153,8 @@ fn test_encode_sep_error_handling() {
             let (data, parity) = shards.split_at_mut(9);
 
             assert_eq!(
-                Error::TooFewDataShards,
-                r.encode_sep(data, parity).unwrap_err()
+                ErrorKind::TooFewDataShards,
+                r.encode_sep(data, parity).unwrap_err().kind()
             );
         }
         {
@@ -2448,8 +3162,8 @@ fn test_encode_sep_error_handling() {
             let (data, parity) = shards.split_at_mut(11);
 
             assert_eq!(
-                Error::TooManyDataShards,
-                r.encode_sep(data, parity).unwrap_err()
+                ErrorKind::TooManyDataShards,
+                r.encode_sep(data, parity).unwrap_err().kind()
             );
         }
         {
@@ -2457,8 +3171,8 @@ fn test_encode_sep_error_handling() {
             let (data, parity) = shards.split_at_mut(10);
 
             assert_eq!(
-                Error::TooFewParityShards,
-                r.encode_sep(data, parity).unwrap_err()
+                ErrorKind::TooFewParityShards,
+                r.encode_sep(data, parity).unwrap_err().kind()
             );
         }
         {
@@ -2466,8 +3180,8 @@ fn test_encode_sep_error_handling() {
             let (data, parity) = shards.split_at_mut(10);
 
             assert_eq!(
-                Error::TooManyParityShards,
-                r.encode_sep(data, parity).unwrap_err()
+                ErrorKind::TooManyParityShards,
+                r.encode_sep(data, parity).unwrap_err().kind()
             );
         }
     }
@@ -2496,8 +3210,8 @@ fn test_encode_sep_error_handling() {
             let mut parity_refs = convert_2D_slices!(parity=>to_mut_vec &mut [u8]);
 
             assert_eq!(
-                Error::TooFewDataShards,
-                r.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
+                ErrorKind::TooFewDataShards,
+                r.encode_sep(&data_refs, &mut parity_refs).unwrap_err().kind()
             );
         }
         {
@@ -2512,8 +3226,8 @@ fn test_encode_sep_error_handling() {
             let mut parity_refs = convert_2D_slices!(parity=>to_mut_vec &mut [u8]);
 
             assert_eq!(
-                Error::TooManyDataShards,
-                r.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
+                ErrorKind::TooManyDataShards,
+                r.encode_sep(&data_refs, &mut parity_refs).unwrap_err().kind()
             );
         }
         {
@@ -2528,8 +3242,8 @@ fn test_encode_sep_error_handling() {
             let mut parity_refs = convert_2D_slices!(parity=>to_mut_vec &mut [u8]);
 
             assert_eq!(
-                Error::TooFewParityShards,
-                r.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
+                ErrorKind::TooFewParityShards,
+                r.encode_sep(&data_refs, &mut parity_refs).unwrap_err().kind()
             );
         }
         {
@@ -2544,8 +3258,8 @@ fn test_encode_sep_error_handling() {
             let mut parity_refs = convert_2D_slices!(parity=>to_mut_vec &mut [u8]);
 
             assert_eq!(
-                Error::TooManyParityShards,
-                r.encode_sep(&data_refs, &mut parity_refs).unwrap_err()
+                ErrorKind::TooManyParityShards,
+                r.encode_sep(&data_refs, &mut parity_refs).unwrap_err().kind()
             );
         }
     }
@@ -2563,24 +3277,24 @@ fn test_encode_single_error_handling() {
         }
 
         assert_eq!(
-            Error::InvalidIndex,
-            r.encode_single(10, &mut shards).unwrap_err()
+            ErrorKind::InvalidIndex,
+            r.encode_single(10, &mut shards).unwrap_err().kind()
         );
         assert_eq!(
-            Error::InvalidIndex,
-            r.encode_single(11, &mut shards).unwrap_err()
+            ErrorKind::InvalidIndex,
+            r.encode_single(11, &mut shards).unwrap_err().kind()
         );
         assert_eq!(
-            Error::InvalidIndex,
-            r.encode_single(12, &mut shards).unwrap_err()
+            ErrorKind::InvalidIndex,
+            r.encode_single(12, &mut shards).unwrap_err().kind()
         );
         assert_eq!(
-            Error::InvalidIndex,
-            r.encode_single(13, &mut shards).unwrap_err()
+            ErrorKind::InvalidIndex,
+            r.encode_single(13, &mut shards).unwrap_err().kind()
         );
         assert_eq!(
-            Error::InvalidIndex,
-            r.encode_single(14, &mut shards).unwrap_err()
+            ErrorKind::InvalidIndex,
+            r.encode_single(14, &mut shards).unwrap_err().kind()
         );
     }
     {
@@ -2596,24 +3310,24 @@ fn test_encode_single_error_handling() {
         }
 
         assert_eq!(
-            Error::InvalidIndex,
-            r.encode_single(10, &mut slice_refs).unwrap_err()
+            ErrorKind::InvalidIndex,
+            r.encode_single(10, &mut slice_refs).unwrap_err().kind()
         );
         assert_eq!(
-            Error::InvalidIndex,
-            r.encode_single(11, &mut slice_refs).unwrap_err()
+            ErrorKind::InvalidIndex,
+            r.encode_single(11, &mut slice_refs).unwrap_err().kind()
         );
         assert_eq!(
-            Error::InvalidIndex,
-            r.encode_single(12, &mut slice_refs).unwrap_err()
+            ErrorKind::InvalidIndex,
+            r.encode_single(12, &mut slice_refs).unwrap_err().kind()
         );
         assert_eq!(
-            Error::InvalidIndex,
-            r.encode_single(13, &mut slice_refs).unwrap_err()
+            ErrorKind::InvalidIndex,
+            r.encode_single(13, &mut slice_refs).unwrap_err().kind()
         );
         assert_eq!(
-            Error::InvalidIndex,
-            r.encode_single(14, &mut slice_refs).unwrap_err()
+            ErrorKind::InvalidIndex,
+            r.encode_single(14, &mut slice_refs).unwrap_err().kind()
         );
     }
 }
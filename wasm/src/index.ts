@@ -29,6 +29,10 @@ export class ReedSolomonErasure {
     public static readonly RESULT_ERROR_EMPTY_SHARD = 11;
     public static readonly RESULT_ERROR_INVALID_SHARD_FLAGS = 12;
     public static readonly RESULT_ERROR_INVALID_INDEX = 13;
+    public static readonly RESULT_ERROR_SINGULAR_MATRIX = 14;
+    public static readonly RESULT_ERROR_INVALID_FIELD_ELEMENT = 15;
+    public static readonly RESULT_ERROR_DUPLICATE_SHARD = 16;
+    public static readonly RESULT_ERROR_UNKNOWN = 255;
 
     /**
      * Automagical method that will try to detect environment (Node.js or browser) and load *.wasm file from current directory
@@ -22,24 +22,36 @@ pub const RESULT_ERROR_TOO_FEW_SHARDS_PRESENT: u8 = 10;
 pub const RESULT_ERROR_EMPTY_SHARD: u8 = 11;
 pub const RESULT_ERROR_INVALID_SHARD_FLAGS: u8 = 12;
 pub const RESULT_ERROR_INVALID_INDEX: u8 = 13;
+pub const RESULT_ERROR_SINGULAR_MATRIX: u8 = 14;
+pub const RESULT_ERROR_INVALID_FIELD_ELEMENT: u8 = 15;
+pub const RESULT_ERROR_DUPLICATE_SHARD: u8 = 16;
+// Any error kind without a code of its own.
+pub const RESULT_ERROR_UNKNOWN: u8 = 255;
 
 fn result_to_number(result: Result<(), Error>) -> u8 {
-    return match result {
-        Ok(()) => RESULT_OK,
-        Err(Error::TooFewShards) => RESULT_ERROR_TOO_FEW_SHARDS,
-        Err(Error::TooManyShards) => RESULT_ERROR_TOO_MANY_SHARDS,
-        Err(Error::TooFewDataShards) => RESULT_ERROR_TOO_FEW_DATA_SHARDS,
-        Err(Error::TooManyDataShards) => RESULT_ERROR_TOO_MANY_DATA_SHARDS,
-        Err(Error::TooFewParityShards) => RESULT_ERROR_TOO_FEW_PARITY_SHARDS,
-        Err(Error::TooManyParityShards) => RESULT_ERROR_TOO_MANY_PARITY_SHARDS,
-        Err(Error::TooFewBufferShards) => RESULT_ERROR_TOO_FEW_BUFFER_SHARDS,
-        Err(Error::TooManyBufferShards) => RESULT_ERROR_TOO_MANY_BUFFER_SHARDS,
-        Err(Error::IncorrectShardSize) => RESULT_ERROR_INCORRECT_SHARD_SIZE,
-        Err(Error::TooFewShardsPresent) => RESULT_ERROR_TOO_FEW_SHARDS_PRESENT,
-        Err(Error::EmptyShard) => RESULT_ERROR_EMPTY_SHARD,
-        Err(Error::InvalidShardFlags) => RESULT_ERROR_INVALID_SHARD_FLAGS,
-        Err(Error::InvalidIndex) => RESULT_ERROR_INVALID_INDEX,
+    let kind = match result {
+        Ok(()) => return RESULT_OK,
+        Err(e) => e.kind(),
     };
+    match kind {
+        ErrorKind::TooFewShards => RESULT_ERROR_TOO_FEW_SHARDS,
+        ErrorKind::TooManyShards => RESULT_ERROR_TOO_MANY_SHARDS,
+        ErrorKind::TooFewDataShards => RESULT_ERROR_TOO_FEW_DATA_SHARDS,
+        ErrorKind::TooManyDataShards => RESULT_ERROR_TOO_MANY_DATA_SHARDS,
+        ErrorKind::TooFewParityShards => RESULT_ERROR_TOO_FEW_PARITY_SHARDS,
+        ErrorKind::TooManyParityShards => RESULT_ERROR_TOO_MANY_PARITY_SHARDS,
+        ErrorKind::TooFewBufferShards => RESULT_ERROR_TOO_FEW_BUFFER_SHARDS,
+        ErrorKind::TooManyBufferShards => RESULT_ERROR_TOO_MANY_BUFFER_SHARDS,
+        ErrorKind::IncorrectShardSize => RESULT_ERROR_INCORRECT_SHARD_SIZE,
+        ErrorKind::TooFewShardsPresent => RESULT_ERROR_TOO_FEW_SHARDS_PRESENT,
+        ErrorKind::EmptyShard => RESULT_ERROR_EMPTY_SHARD,
+        ErrorKind::InvalidShardFlags => RESULT_ERROR_INVALID_SHARD_FLAGS,
+        ErrorKind::InvalidIndex => RESULT_ERROR_INVALID_INDEX,
+        ErrorKind::SingularMatrix => RESULT_ERROR_SINGULAR_MATRIX,
+        ErrorKind::InvalidFieldElement => RESULT_ERROR_INVALID_FIELD_ELEMENT,
+        ErrorKind::DuplicateShard => RESULT_ERROR_DUPLICATE_SHARD,
+        _ => RESULT_ERROR_UNKNOWN,
+    }
 }
 
 #[wasm_bindgen]
@@ -62,12 +74,11 @@ pub fn reconstruct(
     let reed_solomon = ReedSolomon::new(data_shards, parity_shards).unwrap();
     let shard_size = shards.len() / (data_shards + parity_shards);
 
-    let shards_available_slice_iter = shards_available.iter().map(|&num| num == 1u8);
+    let shards_available: Vec<bool> = shards_available.iter().map(|&num| num == 1u8).collect();
 
-    let mut separate_slice_shards: Vec<_> = shards
-        .chunks_exact_mut(shard_size)
-        .zip(shards_available_slice_iter)
-        .collect();
+    let mut separate_slice_shards: Vec<_> = shards.chunks_exact_mut(shard_size).collect();
 
-    return result_to_number(reed_solomon.reconstruct_data(&mut separate_slice_shards));
+    return result_to_number(
+        reed_solomon.reconstruct_data_with_flags(&mut separate_slice_shards, &shards_available),
+    );
 }